@@ -5,7 +5,11 @@ pub type Script = Box<dyn Fn(&Situation) -> Vec<ActionEvent> + Send + Sync>;
 pub struct Action {
     pub transient: bool,
     pub input: Option<String>,
+    /// Frames a recognized input waits in the buffer, overrides the player's timing profile
+    pub input_buffer: Option<usize>,
     pub requirement: ActionRequirement,
+    /// Has hits, used to tell startup apart from dashes and the like
+    pub attack: bool,
     pub script: Script,
 }
 
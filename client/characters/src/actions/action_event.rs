@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 
 use foundation::{
-    ActionId, Animation, Area, PickupRequest, RoninAnimation, SoundRequest, StatusCondition,
-    StatusFlag, VfxRequest, VoiceLine,
+    ActionId, Animation, Area, ItemId, PickupRequest, PuppetRequest, RoninAnimation, SoundRequest,
+    StatusCondition, StatusFlag, VfxRequest, VoiceLine,
 };
 
 use crate::{FlashRequest, GaugeType, Movement};
@@ -29,6 +29,7 @@ pub enum ActionEvent {
     SnapToOpponent {
         sideswitch: bool,
     },
+    ThrowTech, // Sent by the one being thrown, breaks the throw for both
     FlipVisuals,
     HitStun(usize),
     BlockStun(usize),
@@ -44,6 +45,11 @@ pub enum ActionEvent {
     AbsoluteVisualEffect(VfxRequest),
     ExpandHurtbox(Area, usize), // New area, how long it should hang around
     SpawnPickup(PickupRequest),
+    SpawnPuppet(PuppetRequest),
+    CommandPuppets(ActionId), // All of the owner's puppets start this action
+    DespawnPuppets,
+    /// Uses up one charge
+    ConsumeItem(ItemId),
     #[default]
     Noop, // makes writing macros easier
     End, // Ends the move, return to neutral
@@ -100,6 +106,9 @@ impl std::fmt::Debug for ActionEvent {
             ActionEvent::SnapToOpponent { sideswitch } => {
                 write!(f, "SnapToOpponent - {sideswitch}")
             }
+            ActionEvent::ThrowTech => {
+                write!(f, "ThrowTech")
+            }
             ActionEvent::HitStun(duration) => {
                 write!(f, "HitStun - {duration:?}")
             }
@@ -142,6 +151,18 @@ impl std::fmt::Debug for ActionEvent {
             ActionEvent::SpawnPickup(pickup_request) => {
                 write!(f, "SpawnPickup - {pickup_request:?}")
             }
+            ActionEvent::SpawnPuppet(puppet_request) => {
+                write!(f, "SpawnPuppet - {puppet_request:?}")
+            }
+            ActionEvent::CommandPuppets(action_id) => {
+                write!(f, "CommandPuppets - {action_id:?}")
+            }
+            ActionEvent::DespawnPuppets => {
+                write!(f, "DespawnPuppets")
+            }
+            ActionEvent::ConsumeItem(item_id) => {
+                write!(f, "ConsumeItem - {item_id:?}")
+            }
             ActionEvent::FlipVisuals => {
                 write!(f, "FlipVisuals")
             }
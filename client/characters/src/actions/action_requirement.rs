@@ -11,7 +11,7 @@ pub enum ActionRequirement {
     AnyActionOngoing,
     ActionOngoing(Vec<ActionId>),
     ActionNotOngoing(Vec<ActionId>),
-    ItemOwned(ItemId),
+    ItemOwned(ItemId), // Consumables need a charge left
     ResourceFull(GaugeType),
     ResourceValue(GaugeType, i32),
     ButtonPressed(GameButton),
@@ -29,6 +29,47 @@ impl ActionRequirement {
         self_id: ActionId,
         windows: &Vec<CancelType>,
         situation: &Situation,
+    ) -> bool {
+        self.first_failure(self_id, windows, situation).is_none()
+    }
+
+    /// Returns the innermost requirement that didn't pass, used to explain rejections
+    /// An Or where none of the options passed is returned as a whole
+    pub fn first_failure(
+        &self,
+        self_id: ActionId,
+        windows: &Vec<CancelType>,
+        situation: &Situation,
+    ) -> Option<&ActionRequirement> {
+        match self {
+            ActionRequirement::And(list) => list
+                .iter()
+                .find_map(|inner| inner.first_failure(self_id, windows, situation)),
+            ActionRequirement::Or(list) => {
+                if list
+                    .iter()
+                    .any(|inner| inner.first_failure(self_id, windows, situation).is_none())
+                {
+                    None
+                } else {
+                    Some(self)
+                }
+            }
+            _ => {
+                if self.check_leaf(self_id, windows, situation) {
+                    None
+                } else {
+                    Some(self)
+                }
+            }
+        }
+    }
+
+    fn check_leaf(
+        &self,
+        self_id: ActionId,
+        windows: &Vec<CancelType>,
+        situation: &Situation,
     ) -> bool {
         match self {
             ActionRequirement::None => true,
@@ -64,21 +105,8 @@ impl ActionRequirement {
             ActionRequirement::ButtonPressed(button) => situation.held_buttons.contains(button),
             ActionRequirement::ButtonNotPressed(button) => !situation.held_buttons.contains(button),
             ActionRequirement::StatusNotActive(status) => !situation.status_flags.contains(status),
-            ActionRequirement::And(list) => {
-                for inner in list {
-                    if !inner.check(self_id, windows, situation) {
-                        return false;
-                    }
-                }
-                true
-            }
-            ActionRequirement::Or(list) => {
-                for inner in list {
-                    if inner.check(self_id, windows, situation) {
-                        return true;
-                    }
-                }
-                false
+            ActionRequirement::And(_) | ActionRequirement::Or(_) => {
+                unreachable!("Compound requirements are handled in first_failure")
             }
             ActionRequirement::Starter(category) => {
                 if situation.stunned {
@@ -130,3 +158,43 @@ impl ActionRequirement {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn first_failure_finds_innermost_requirement() {
+        let situation = Situation {
+            grounded: true,
+            ..Default::default()
+        };
+        let id = ActionId::ForwardDash;
+
+        let requirement = ActionRequirement::And(vec![
+            ActionRequirement::Grounded,
+            ActionRequirement::And(vec![ActionRequirement::None, ActionRequirement::Airborne]),
+        ]);
+        assert!(matches!(
+            requirement.first_failure(id, &vec![], &situation),
+            Some(ActionRequirement::Airborne)
+        ));
+        assert!(!requirement.check(id, &vec![], &situation));
+
+        let requirement = ActionRequirement::Or(vec![
+            ActionRequirement::Airborne,
+            ActionRequirement::AnyActionOngoing,
+        ]);
+        assert!(matches!(
+            requirement.first_failure(id, &vec![], &situation),
+            Some(ActionRequirement::Or(_))
+        ));
+
+        let requirement = ActionRequirement::Or(vec![
+            ActionRequirement::Airborne,
+            ActionRequirement::Grounded,
+        ]);
+        assert!(requirement.first_failure(id, &vec![], &situation).is_none());
+        assert!(requirement.check(id, &vec![], &situation));
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect, Event)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect, Event, Serialize, Deserialize)]
 pub struct Movement {
     pub amount: Vec2,
     pub duration: usize,
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use foundation::{Area, Model};
 
@@ -12,6 +13,12 @@ pub struct ToHit {
     pub model: Option<Model>,
     pub hits: usize,
     pub projectile: bool,
+    /// Juggle points spent if this launches
+    pub juggle_cost: usize,
+    pub hitstun_decay: bool,
+    pub behaviour: ProjectileBehaviour,
+    /// When projectiles clash, the sturdier one keeps going
+    pub durability: usize,
 }
 
 impl Default for ToHit {
@@ -25,11 +32,28 @@ impl Default for ToHit {
             model: Default::default(),
             hits: 1,
             projectile: false,
+            juggle_cost: 1,
+            hitstun_decay: true,
+            behaviour: ProjectileBehaviour::default(),
+            durability: 1,
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, Reflect, Eq, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Default, Serialize, Deserialize)]
+pub enum ProjectileBehaviour {
+    /// Goes where velocity and gravity take it
+    #[default]
+    Ballistic,
+    /// Turns toward the opponent, radians per second
+    Homing(f32),
+    /// Flies back to the owner after this many frames
+    Boomerang(usize),
+    /// Can't hit anything for this many frames, pair with zero velocity
+    Trap(usize),
+}
+
+#[derive(Clone, Copy, Debug, Reflect, Eq, PartialEq, Default, Serialize, Deserialize)]
 pub enum AttackHeight {
     Low,
     #[default]
@@ -49,7 +73,7 @@ impl Default for BlockType {
     }
 }
 
-#[derive(Debug, Clone, Copy, Reflect)]
+#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize)]
 pub struct CharacterStateBoxes {
     pub head: Area,
     pub chest: Area,
@@ -57,7 +81,7 @@ pub struct CharacterStateBoxes {
     pub pushbox: Area,
 }
 
-#[derive(Debug, Clone, Copy, Reflect)]
+#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize)]
 pub struct CharacterBoxes {
     pub standing: CharacterStateBoxes,
     pub crouching: CharacterStateBoxes,
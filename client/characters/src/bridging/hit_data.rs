@@ -9,6 +9,15 @@ pub struct HitInfo {
     pub airborne: bool,
     pub hitbox_pos: Vec2,
     pub defender_stats: Stats,
+    pub counter: Option<CounterHit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterHit {
+    /// Defender got hit before their move had an active hitbox
+    Counter,
+    /// Defender got hit after their move was done attacking
+    Punish,
 }
 
 #[derive(Clone)]
@@ -5,5 +5,5 @@ mod hit_data;
 mod situation;
 
 pub use action_tracker::ActionTracker;
-pub use hit_data::{HitEffect, HitInfo};
+pub use hit_data::{CounterHit, HitEffect, HitInfo};
 pub use situation::Situation;
@@ -16,6 +16,8 @@ pub struct Situation {
     pub stats: Stats,
     pub stick_position: StickPosition,
     pub held_buttons: HashSet<GameButton>,
+    /// Went down this frame
+    pub pressed_buttons: HashSet<GameButton>,
     pub position: Vec3,
     pub combo: Combo,
     pub stunned: bool,
@@ -2,8 +2,8 @@ use std::sync::Arc;
 
 use bevy::{platform::collections::HashMap, prelude::*};
 use foundation::{
-    ActionCategory, ActionId, Animation, GameButton, SimpleState, Sound, VfxRequest, VisualEffect,
-    METER_BAR_SEGMENT,
+    ActionCategory, ActionId, Animation, Armor, GameButton, ProjectileGuard, SimpleState, Sound,
+    StatusCondition, StatusFlag, VfxRequest, VisualEffect, METER_BAR_SEGMENT,
 };
 
 use crate::{
@@ -88,6 +88,7 @@ impl Input {
 #[derive(Default)]
 pub struct ActionBuilder {
     input: Option<Input>,
+    pub input_buffer: Option<usize>,
     transient: bool,
     pub state: Option<SimpleState>,
     pub category: ActionCategory,
@@ -146,13 +147,20 @@ impl ActionBuilder {
         }
     }
 
-    pub fn with_input(self, input: &'static str) -> Self {
+    pub fn with_input(self, input: impl Into<String>) -> Self {
         Self {
             input: Some(Input::Motion(input.into())),
             ..self
         }
     }
 
+    pub fn with_input_buffer(self, frames: usize) -> Self {
+        Self {
+            input_buffer: Some(frames),
+            ..self
+        }
+    }
+
     pub fn with_meter_cost(self) -> Self {
         Self {
             needs_meter: true,
@@ -297,6 +305,37 @@ impl ActionBuilder {
         self
     }
 
+    /// Armor from the first frame until the last, or until it runs out of hits
+    pub fn with_armor(self, first_frame: usize, last_frame: usize, armor: Armor) -> Self {
+        debug_assert!(first_frame <= last_frame);
+        self.static_events_on_frame(
+            first_frame,
+            vec![ActionEvent::Condition(StatusCondition {
+                flag: StatusFlag::Armor(armor),
+                expiration: Some(last_frame - first_frame + 1),
+                ..default()
+            })],
+        )
+    }
+
+    /// Reflects or absorbs projectiles that touch the user
+    pub fn with_projectile_guard(
+        self,
+        first_frame: usize,
+        last_frame: usize,
+        guard: ProjectileGuard,
+    ) -> Self {
+        debug_assert!(first_frame <= last_frame);
+        self.static_events_on_frame(
+            first_frame,
+            vec![ActionEvent::Condition(StatusCondition {
+                flag: StatusFlag::ProjectileGuard(guard),
+                expiration: Some(last_frame - first_frame + 1),
+                ..default()
+            })],
+        )
+    }
+
     pub fn with_vfx_on_frame(self, frame: usize, effect: VisualEffect, tf: Transform) -> Self {
         self.dyn_events_on_frame(
             frame,
@@ -425,7 +464,9 @@ impl ActionBuilder {
         Action {
             transient: self.transient,
             input: self.build_input(),
+            input_buffer: self.input_buffer,
             requirement: self.build_requirements(),
+            attack: false,
             script: Box::new(self.build_script()),
         }
     }
@@ -1,18 +1,21 @@
 use std::{f32::consts::PI, sync::Arc};
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use foundation::{
-    ActionCategory, ActionId, Animation, Area, CancelType, Facing, GameButton, Icon, Model,
-    RingPulse, SimpleState, Smear, Sound, StatusCondition, StatusFlag, VfxRequest, VisualEffect,
-    VoiceLine, BIG_HIT_THRESHOLD, HIGH_OPENER_COLOR, JACKPOT_COLOR, JACKPOT_METER_GAIN,
-    JACKPOT_RING_BASE_COLOR, LOW_OPENER_COLOR, MID_OPENER_COLOR, ON_BLOCK_HITSTOP, ON_HIT_HITSTOP,
-    SMALL_HIT_THRESHOLD, THROW_TECH_RING_BASE_COLOR, THROW_TECH_RING_EDGE_COLOR,
+    ActionCategory, ActionId, Animation, Area, CancelType, Facing, Icon, Model, RingPulse,
+    SimpleState, Smear, Sound, StatusCondition, StatusFlag, VfxRequest, VisualEffect, VoiceLine,
+    BIG_HIT_THRESHOLD, COUNTER_HIT_COLOR, COUNTER_RING_BASE_COLOR, HIGH_OPENER_COLOR,
+    JACKPOT_COLOR, JACKPOT_METER_GAIN, JACKPOT_RING_BASE_COLOR, LOW_OPENER_COLOR, MID_OPENER_COLOR,
+    ON_BLOCK_HITSTOP, ON_HIT_HITSTOP, PUNISH_COLOR, SMALL_HIT_THRESHOLD,
+    THROW_TECH_RING_BASE_COLOR, THROW_TECH_RING_EDGE_COLOR, THROW_TECH_WINDOW,
 };
 
 use crate::{
-    Action, ActionEvent, ActionRequirement, Attack, AttackHeight, BlockType, FlashRequest,
-    GaugeType, HitEffect, HitInfo, Hitbox, Lifetime, Movement, OnHitEffect, Situation, ToHit,
+    Action, ActionEvent, ActionRequirement, Attack, AttackHeight, BlockType, CounterHit,
+    FlashRequest, GaugeType, HitEffect, HitInfo, Hitbox, Lifetime, Movement, OnHitEffect,
+    ProjectileBehaviour, Situation, ToHit,
 };
 
 use super::{ActionBuilder, CharacterUniversals, DynamicEvents, Events};
@@ -56,7 +59,7 @@ impl Default for SubBuilder {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Stun {
     Relative(i32),
     Absolute(usize),
@@ -74,28 +77,23 @@ pub struct AttackBuilder {
     hits: Vec<(usize, HitBuilder)>,
 }
 
-impl AttackBuilder {
-    pub fn special() -> Self {
+impl From<ActionBuilder> for AttackBuilder {
+    fn from(action_builder: ActionBuilder) -> Self {
         Self {
-            action_builder: ActionBuilder::special(),
+            action_builder,
             ..default()
         }
     }
+}
 
-    pub fn normal() -> Self {
+impl AttackBuilder {
+    pub fn special() -> Self {
         Self {
-            action_builder: ActionBuilder::normal(),
+            action_builder: ActionBuilder::special(),
             ..default()
         }
     }
 
-    pub fn button(btn: GameButton) -> Self {
-        Self {
-            action_builder: ActionBuilder::button(btn),
-            ..Self::normal()
-        }
-    }
-
     pub fn with_character_universals(self, universals: CharacterUniversals) -> Self {
         Self {
             action_builder: self.action_builder.with_character_universals(universals),
@@ -103,28 +101,13 @@ impl AttackBuilder {
         }
     }
 
-    pub fn follow_up_from(self, actions: Vec<ActionId>) -> Self {
-        Self {
-            action_builder: self.action_builder.follow_up_from(actions),
-            ..self
-        }
-    }
-
-    pub fn with_input(self, input: &'static str) -> Self {
+    pub fn with_input(self, input: impl Into<String>) -> Self {
         Self {
             action_builder: self.action_builder.with_input(input),
             ..self
         }
     }
 
-    #[allow(unused)] // No metered attack builder attacks yet
-    pub fn with_meter_cost(self) -> Self {
-        Self {
-            action_builder: self.action_builder.with_meter_cost(),
-            ..self
-        }
-    }
-
     #[allow(unused)]
     pub fn with_charge(self) -> Self {
         Self {
@@ -133,13 +116,6 @@ impl AttackBuilder {
         }
     }
 
-    pub fn with_sound(self, sound: Sound) -> Self {
-        Self {
-            action_builder: self.action_builder.with_sound(sound),
-            ..self
-        }
-    }
-
     pub fn with_hit_on_frame(mut self, frame: usize, mut hit: HitBuilder) -> Self {
         if self.action_builder.state == Some(SimpleState::Air) {
             hit = {
@@ -186,11 +162,6 @@ impl AttackBuilder {
         self
     }
 
-    pub fn with_vfx_on_frame(mut self, frame: usize, effect: VisualEffect, tf: Transform) -> Self {
-        self.action_builder = self.action_builder.with_vfx_on_frame(frame, effect, tf);
-        self
-    }
-
     pub fn with_animation(self, animation: impl Into<Animation>) -> Self {
         Self {
             action_builder: self.action_builder.with_animation(animation),
@@ -205,12 +176,6 @@ impl AttackBuilder {
         }
     }
 
-    pub fn crouching(self) -> Self {
-        Self {
-            action_builder: self.action_builder.crouching(),
-            ..self
-        }
-    }
     pub fn air_only(self) -> Self {
         Self {
             action_builder: self.action_builder.air_only(),
@@ -240,13 +205,6 @@ impl AttackBuilder {
         }
     }
 
-    pub fn with_extra_initial_events(self, events: Vec<ActionEvent>) -> Self {
-        Self {
-            action_builder: self.action_builder.static_immediate_events(events),
-            ..self
-        }
-    }
-
     pub fn with_extra_events(self, frame: usize, events: Vec<ActionEvent>) -> Self {
         Self {
             action_builder: self.action_builder.static_events_on_frame(frame, events),
@@ -306,7 +264,9 @@ impl AttackBuilder {
         Action {
             transient: false,
             input: self.action_builder.build_input(),
+            input_buffer: self.action_builder.input_buffer,
             requirement: self.action_builder.build_requirements(),
+            attack: !self.hits.is_empty(),
             script: Box::new(self.build_script()),
         }
     }
@@ -331,6 +291,10 @@ pub struct HitBuilder {
     hitbox_lifetime: Lifetime,
     additional_events: Events,
     smear: Option<SmearSpec>,
+    juggle_cost: Option<usize>,
+    skip_hitstun_decay: bool,
+    behaviour: ProjectileBehaviour,
+    durability: Option<usize>,
 }
 
 impl HitBuilder {
@@ -357,6 +321,10 @@ impl HitBuilder {
             gravity: self.gravity,
             model: self.spawn,
             hits: self.hit_count,
+            juggle_cost: self.juggle_cost.unwrap_or(1),
+            hitstun_decay: !self.skip_hitstun_decay,
+            behaviour: self.behaviour,
+            durability: self.durability.unwrap_or(1),
         };
 
         let on_hit = match self.sub_builder {
@@ -489,6 +457,24 @@ impl HitBuilder {
         }
     }
 
+    /// Only projectiles have a behaviour, so this makes the hit one
+    pub fn with_behaviour(self, behaviour: ProjectileBehaviour) -> Self {
+        Self {
+            behaviour,
+            projectile: true,
+            ..self
+        }
+    }
+
+    /// Only projectiles clash, so this makes the hit one
+    pub fn with_durability(self, durability: usize) -> Self {
+        Self {
+            durability: Some(durability),
+            projectile: true,
+            ..self
+        }
+    }
+
     pub fn with_disjoint(self) -> Self {
         Self {
             expand_hurtbox: None,
@@ -534,17 +520,33 @@ impl HitBuilder {
         self.with_strike_builder(move |sb| sb.launches(impulse))
     }
 
+    pub fn with_counter_hit_bonus(self, bonus: CounterBonus) -> Self {
+        self.with_strike_builder(move |sb| sb.with_counter_hit_bonus(bonus))
+    }
+
+    pub fn with_punish_bonus(self, bonus: CounterBonus) -> Self {
+        self.with_strike_builder(move |sb| sb.with_punish_bonus(bonus))
+    }
+
+    pub fn with_juggle_cost(self, juggle_cost: usize) -> Self {
+        Self {
+            juggle_cost: Some(juggle_cost),
+            ..self
+        }
+    }
+
+    pub fn without_hitstun_decay(self) -> Self {
+        Self {
+            skip_hitstun_decay: true,
+            ..self
+        }
+    }
+
     pub fn sword(self) -> Self {
         self.with_strike_builder(|sb| sb.with_chip_damage(5).with_sharpness_scaling(5))
             .with_disjoint()
     }
 
-    pub fn hits_overhead(self) -> Self {
-        self.with_strike_builder(|sb| sb.with_height(AttackHeight::High))
-    }
-    pub fn hits_low(self) -> Self {
-        self.with_strike_builder(|sb| sb.with_height(AttackHeight::Low))
-    }
     pub fn with_attack_height(self, height: AttackHeight) -> Self {
         self.with_strike_builder(|sb| sb.with_height(height))
     }
@@ -628,15 +630,27 @@ pub fn build_throw_effect(
                 defender: vec![Movement::impulse(Vec2::X * -2.0).into()],
             }
         } else {
+            let mut defender = vec![
+                ActionEvent::SnapToOpponent { sideswitch },
+                ActionEvent::StartAction(target_action),
+            ];
+
+            if hit_data.counter.is_some() {
+                // Grabbing someone mid-move can't be teched, cleared by the target action
+                defender.push(ActionEvent::Condition(StatusCondition {
+                    flag: StatusFlag::Untechable,
+                    // Outlasts the tech window, even if the target action is cut short
+                    expiration: Some(THROW_TECH_WINDOW + 1),
+                    ..default()
+                }));
+            }
+
             HitEffect {
                 attacker: vec![
                     ActionEvent::StartAction(on_hit_action),
                     ActionEvent::Sound(Sound::PastaPat.into()),
                 ],
-                defender: vec![
-                    ActionEvent::SnapToOpponent { sideswitch },
-                    ActionEvent::StartAction(target_action),
-                ],
+                defender,
             }
         }
     })
@@ -655,6 +669,18 @@ impl Default for HitStun {
     }
 }
 
+/// Extra oomph for hitting someone during their startup (counter hit) or recovery (punish)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct CounterBonus {
+    #[serde(default)]
+    pub hitstun: usize,
+    #[serde(default)]
+    pub damage: i32,
+    /// Replaces the regular stun
+    #[serde(default)]
+    pub launch: Option<Vec2>,
+}
+
 #[derive(Debug, Clone)]
 pub struct StrikeEffectBuilder {
     block_stun: Stun,
@@ -670,6 +696,8 @@ pub struct StrikeEffectBuilder {
     on_hit_effects: Events,
     cancel: Option<(CancelType, Option<usize>)>,
     juggle_impulse: Vec2,
+    counter_hit_bonus: CounterBonus,
+    punish_bonus: CounterBonus,
 }
 impl Default for StrikeEffectBuilder {
     fn default() -> Self {
@@ -688,6 +716,15 @@ impl Default for StrikeEffectBuilder {
             chip_damage: 1,
             sharpness_scaling: 0,
             juggle_impulse: Vec2::new(-1.0, 5.0),
+            counter_hit_bonus: CounterBonus {
+                hitstun: 4,
+                ..default()
+            },
+            punish_bonus: CounterBonus {
+                hitstun: 2,
+                damage: 2,
+                ..default()
+            },
         }
         .with_distance_on_hit(0.7)
         .with_distance_on_block(1.2)
@@ -734,6 +771,20 @@ impl StrikeEffectBuilder {
         self
     }
 
+    pub fn with_counter_hit_bonus(self, counter_hit_bonus: CounterBonus) -> Self {
+        Self {
+            counter_hit_bonus,
+            ..self
+        }
+    }
+
+    pub fn with_punish_bonus(self, punish_bonus: CounterBonus) -> Self {
+        Self {
+            punish_bonus,
+            ..self
+        }
+    }
+
     pub fn with_damage(self, damage: i32) -> Self {
         Self {
             base_damage: damage,
@@ -911,8 +962,15 @@ impl StrikeEffectBuilder {
                     ],
                 }
             } else {
+                let bonus = match hit_data.counter {
+                    Some(CounterHit::Counter) => self.counter_hit_bonus,
+                    Some(CounterHit::Punish) => self.punish_bonus,
+                    None => CounterBonus::default(),
+                };
+
                 let damage = ((self.base_damage + self.sharpness_scaling * sharpness) as f32
-                    * (1.0 + jackpot_multiplier)) as i32;
+                    * (1.0 + jackpot_multiplier)) as i32
+                    + bonus.damage;
                 let voice_line_event = if damage >= BIG_HIT_THRESHOLD {
                     ActionEvent::SayVoiceLine(VoiceLine::BigHit)
                 } else if damage >= SMALL_HIT_THRESHOLD {
@@ -921,20 +979,40 @@ impl StrikeEffectBuilder {
                     ActionEvent::Noop
                 };
 
-                let (launcher, stun_event) = match self.hit_stun {
-                    HitStun::Stun(stun) => (
+                let (launcher, stun_event) = match (self.hit_stun.clone(), bonus.launch) {
+                    (_, Some(impulse)) => (true, ActionEvent::LaunchStun(impulse)),
+                    (HitStun::Stun(stun), None) => (
                         false,
                         ActionEvent::HitStun(
                             match stun {
                                 Stun::Relative(advantage) => (recovery + advantage) as usize,
                                 Stun::Absolute(frames) => frames,
-                            } + (jackpot_multiplier * 20.0) as usize,
+                            } + (jackpot_multiplier * 20.0) as usize
+                                + bonus.hitstun,
                         ),
                     ),
-                    HitStun::Launch(vec2) => (true, ActionEvent::LaunchStun(vec2)),
-                    HitStun::Knockdown => (true, ActionEvent::LaunchStun(Vec2::ZERO)),
+                    (HitStun::Launch(vec2), None) => (true, ActionEvent::LaunchStun(vec2)),
+                    (HitStun::Knockdown, None) => (true, ActionEvent::LaunchStun(Vec2::ZERO)),
                 };
 
+                let counter_vfx = hit_data.counter.map(|counter| {
+                    ActionEvent::AbsoluteVisualEffect(VfxRequest {
+                        effect: VisualEffect::RingPulse(RingPulse {
+                            base_color: COUNTER_RING_BASE_COLOR,
+                            edge_color: match counter {
+                                CounterHit::Counter => COUNTER_HIT_COLOR,
+                                CounterHit::Punish => PUNISH_COLOR,
+                            },
+                            rings: 2,
+                            thickness: 0.1,
+                            offset: 0.15,
+                            duration: 0.5,
+                        }),
+                        tf: Transform::from_translation(hit_data.hitbox_pos.extend(0.0)),
+                        ..default()
+                    })
+                });
+
                 let jackpot_events = if jackpot_level != 0 {
                     vec![
                         ActionEvent::Sound(Sound::BoxingBell(jackpot_level).into()),
@@ -984,6 +1062,7 @@ impl StrikeEffectBuilder {
                     ]
                     .into_iter()
                     .chain(jackpot_events)
+                    .chain(counter_vfx)
                     .collect(),
                     defender: self
                         .on_hit_effects
@@ -75,6 +75,8 @@ fn jump(
     Action {
         transient: false,
         input: Some(jump_dir.input(jump_type)),
+        input_buffer: None,
+        attack: false,
         script: Box::new(move |situation: &Situation| {
             /*
             Math for initial jump velocity
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use foundation::{
-    Animation, StatusCondition, StatusFlag, VfxRequest, VisualEffect, ON_THROW_HITSTOP,
+    Animation, GameButton, StatusCondition, StatusFlag, VfxRequest, VisualEffect, ON_THROW_HITSTOP,
+    THROW_TECH_WINDOW,
 };
 
 use crate::{
@@ -16,6 +17,7 @@ pub struct ThrowEffectBuilder {
     damage: i32,
     launch_impulse: Vec2,
     extra_target_events: Vec<ActionEvent>,
+    tech_window: Option<usize>,
 }
 impl ThrowEffectBuilder {
     pub fn new(
@@ -33,6 +35,24 @@ impl ThrowEffectBuilder {
             launch_impulse: Vec2::ZERO,
             extra_target_events: vec![],
             damage: 0,
+            tech_window: Some(THROW_TECH_WINDOW),
+        }
+    }
+
+    /// How many frames the target has to press throw after the grab to tech it
+    /// Counter hit throws stay untechable for the default window, so this can only shorten it
+    pub fn with_tech_window(self, frames: usize) -> Self {
+        debug_assert!(frames <= THROW_TECH_WINDOW);
+        Self {
+            tech_window: Some(frames),
+            ..self
+        }
+    }
+
+    pub fn untechable(self) -> Self {
+        Self {
+            tech_window: None,
+            ..self
         }
     }
 
@@ -58,11 +78,16 @@ impl ThrowEffectBuilder {
     pub fn build(self) -> (Action, Action) {
         debug_assert!(self.lock_duration < self.target_duration);
         debug_assert!(self.lock_duration < self.self_duration);
+        debug_assert!(self
+            .tech_window
+            .is_none_or(|window| window < self.lock_duration));
 
         (
             Action {
                 transient: false,
                 input: None,
+                input_buffer: None,
+                attack: false,
                 script: Box::new(move |situation: &Situation| {
                     if situation.on_frame(0) {
                         return vec![
@@ -90,11 +115,22 @@ impl ThrowEffectBuilder {
                         ];
                     }
 
+                    if self
+                        .tech_window
+                        .is_some_and(|window| situation.elapsed() <= window)
+                        && !situation.status_flags.contains(&StatusFlag::Untechable)
+                        && situation.pressed_buttons.contains(&GameButton::Wrestling)
+                    {
+                        // Flip back, as the throw never happened
+                        return vec![ActionEvent::FlipVisuals, ActionEvent::ThrowTech];
+                    }
+
                     if situation.elapsed() == self.lock_duration {
                         return vec![
                             ActionEvent::LaunchStun(self.launch_impulse),
                             ActionEvent::ModifyResource(GaugeType::Health, -self.damage),
                             ActionEvent::FlipVisuals,
+                            ActionEvent::ClearCondition(StatusFlag::Untechable),
                         ]
                         .into_iter()
                         .chain(self.extra_target_events.clone())
@@ -113,6 +149,8 @@ impl ThrowEffectBuilder {
             Action {
                 transient: false,
                 input: None,
+                input_buffer: None,
+                attack: false,
                 script: Box::new(move |situation: &Situation| {
                     if situation.on_frame(0) {
                         return vec![
@@ -142,3 +180,83 @@ impl ThrowEffectBuilder {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use foundation::{ActionId, RoninAnimation};
+
+    use super::*;
+    use crate::ActionTracker;
+
+    fn tech_attempt(target: &Action, frame: usize, untechable: bool) -> bool {
+        let situation = Situation {
+            tracker: Some(ActionTracker::new(0, false, ActionId::default())),
+            char_frame: frame,
+            held_buttons: [GameButton::Wrestling].into(),
+            pressed_buttons: [GameButton::Wrestling].into(),
+            status_flags: if untechable {
+                [StatusFlag::Untechable].into()
+            } else {
+                default()
+            },
+            ..default()
+        };
+
+        (target.script)(&situation)
+            .iter()
+            .any(|ev| matches!(ev, ActionEvent::ThrowTech))
+    }
+
+    #[test]
+    fn tech_window() {
+        let (target, _) = ThrowEffectBuilder::new(
+            RoninAnimation::StandThrowHit,
+            80,
+            RoninAnimation::StandThrowTarget,
+            30,
+        )
+        .with_tech_window(5)
+        .build();
+
+        assert!(tech_attempt(&target, 1, false));
+        assert!(tech_attempt(&target, 5, false));
+        assert!(!tech_attempt(&target, 6, false));
+        assert!(!tech_attempt(&target, 1, true));
+    }
+
+    #[test]
+    fn untechable_throw() {
+        let (target, _) = ThrowEffectBuilder::new(
+            RoninAnimation::StandThrowHit,
+            80,
+            RoninAnimation::StandThrowTarget,
+            30,
+        )
+        .untechable()
+        .build();
+
+        assert!(!tech_attempt(&target, 1, false));
+    }
+
+    #[test]
+    fn holding_throw_does_not_tech() {
+        let (target, _) = ThrowEffectBuilder::new(
+            RoninAnimation::StandThrowHit,
+            80,
+            RoninAnimation::StandThrowTarget,
+            30,
+        )
+        .build();
+
+        let situation = Situation {
+            tracker: Some(ActionTracker::new(0, false, ActionId::default())),
+            char_frame: 1,
+            held_buttons: [GameButton::Wrestling].into(),
+            ..default()
+        };
+
+        assert!(!(target.script)(&situation)
+            .iter()
+            .any(|ev| matches!(ev, ActionEvent::ThrowTech)));
+    }
+}
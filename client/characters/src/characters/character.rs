@@ -1,24 +1,47 @@
 use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+
 use foundation::{
-    ActionId, Animation, AnimationType, CharacterId, ItemId, Model, Player, Sound, Stats, VoiceLine,
+    ActionId, Animation, AnimationType, CharacterId, ControlScheme, InputTiming, ItemId,
+    MatchRules, Model, Sound, Stats, VoiceLine,
 };
 
-use crate::{resources::GaugeType, Action, CharacterBoxes, Gauge, Item};
+use crate::{
+    resources::GaugeType, Action, CharacterBoxes, CharacterUniversals, Gauge, Item, ItemHandler,
+};
 
 use super::{cpo, ronin};
 
+/// The scripted part of a character, the rest comes from a CharacterDefinition
+pub(crate) struct BaseCharacter {
+    pub universals: CharacterUniversals,
+    pub moves: Vec<(ActionId, Action)>,
+    pub item_handlers: Vec<(ItemId, ItemHandler)>,
+}
+
+/// Named set of material colors, materials that aren't listed keep the model colors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Palette {
+    pub name: String,
+    #[serde(default)]
+    pub colors: Vec<(String, Color)>,
+}
+
 #[derive(Debug, Component)]
 pub struct Character {
     pub(crate) moves: HashMap<ActionId, Action>,
     pub(crate) voicelines: HashMap<VoiceLine, Sound>,
     pub theme_song: Sound,
-    pub colors: HashMap<Player, HashMap<&'static str, Color>>,
+    /// First one is the default
+    pub palettes: Vec<Palette>,
     pub items: HashMap<ItemId, Item>,
     pub model: Model,
     pub boxes: CharacterBoxes,
     pub generic_animations: HashMap<AnimationType, Animation>,
     pub base_stats: Stats,
     pub special_properties: Vec<(GaugeType, Gauge)>,
+    /// Shortcut inputs used by the simple control scheme, on top of the classic ones
+    pub simple_inputs: HashMap<String, ActionId>,
 }
 impl Character {
     // TODO: Consider making a builder for this
@@ -26,7 +49,7 @@ impl Character {
     pub(crate) fn new(
         model: Model,
         theme_song: Sound,
-        p2_colors: HashMap<&'static str, Color>,
+        palettes: Vec<Palette>,
         generic_animations: HashMap<AnimationType, Animation>,
         moves: HashMap<ActionId, Action>,
         items: HashMap<ItemId, Item>,
@@ -34,15 +57,15 @@ impl Character {
         base_stats: Stats,
         special_properties: Vec<(GaugeType, Gauge)>,
         voicelines: HashMap<VoiceLine, Sound>,
+        simple_inputs: HashMap<String, ActionId>,
     ) -> Character {
         debug_assert_eq!(boxes.standing.pushbox.bottom(), 0.0);
+        debug_assert!(!palettes.is_empty());
 
         Self {
             model,
             theme_song,
-            colors: vec![(Player::One, HashMap::new()), (Player::Two, p2_colors)]
-                .into_iter()
-                .collect(),
+            palettes,
             generic_animations,
             moves,
             items,
@@ -50,6 +73,14 @@ impl Character {
             boxes,
             base_stats,
             voicelines,
+            simple_inputs,
+        }
+    }
+
+    /// Banned items are left out of the shop
+    pub fn ban_items(&mut self, rules: &MatchRules) {
+        for (id, item) in &mut self.items {
+            item.banned = rules.is_banned(*id, item.category.kind());
         }
     }
 
@@ -57,11 +88,34 @@ impl Character {
         self.moves.get(&id)
     }
 
-    pub fn get_inputs(&self) -> HashMap<ActionId, String> {
-        self.moves
+    /// Frames an input for the move stays in the buffer
+    pub fn input_buffer(&self, id: ActionId, timing: InputTiming) -> usize {
+        self.get_move(id)
+            .and_then(|action| action.input_buffer)
+            .unwrap_or(timing.move_buffer())
+    }
+
+    pub fn get_inputs(&self, scheme: ControlScheme) -> Vec<(ActionId, String)> {
+        let classic = self
+            .moves
             .iter()
-            .filter_map(|(key, move_data)| move_data.input.clone().map(|input| (*key, input)))
-            .collect()
+            .filter_map(|(key, move_data)| move_data.input.clone().map(|input| (*key, input)));
+
+        match scheme {
+            ControlScheme::Classic => classic.collect(),
+            ControlScheme::Simple => classic
+                .chain(
+                    self.simple_inputs
+                        .iter()
+                        .map(|(input, action)| (*action, input.clone())),
+                )
+                .collect(),
+        }
+    }
+
+    /// Index wraps around, so it is safe to use whatever came over the network
+    pub fn palette(&self, index: usize) -> &Palette {
+        &self.palettes[index % self.palettes.len()]
     }
 
     pub fn get_voiceline(&self, line: VoiceLine) -> Sound {
@@ -80,13 +134,15 @@ impl From<CharacterId> for Character {
 
 #[cfg(test)]
 mod test {
+    use strum::IntoEnumIterator;
+
     use crate::{ActionEvent, ActionRequirement, ActionTracker, Situation};
 
     use super::*;
 
     #[test]
     fn all_moves_end() {
-        for char in [ronin()] {
+        for char in CharacterId::iter().map(Character::from) {
             for (id, mov) in char.moves.iter() {
                 if mov.transient {
                     continue;
@@ -98,6 +154,11 @@ mod test {
                         ..default()
                     }),
                     char_frame: 9999,
+                    resources: crate::Gauges::from_stats(
+                        &char.base_stats,
+                        char.special_properties.clone(),
+                    )
+                    .0,
                     ..default()
                 };
                 let end_events = (mov.script)(&sit);
@@ -112,7 +173,7 @@ mod test {
 
     #[test]
     fn moves_with_inputs_have_starter_requirement() {
-        for char in [ronin()] {
+        for char in CharacterId::iter().map(Character::from) {
             for (id, mov) in char.moves.iter() {
                 debug!("Move ID: {:?}", id);
 
@@ -123,6 +184,56 @@ mod test {
         }
     }
 
+    #[test]
+    fn actions_can_override_input_buffer() {
+        let mut char = Character::from(CharacterId::Ronin);
+        let id = ActionId::ForwardDash;
+        assert_eq!(
+            char.input_buffer(id, InputTiming::Strict),
+            InputTiming::Strict.move_buffer()
+        );
+
+        char.moves.get_mut(&id).unwrap().input_buffer = Some(20);
+        for timing in InputTiming::iter() {
+            assert_eq!(char.input_buffer(id, timing), 20);
+        }
+    }
+
+    #[test]
+    fn shortcuts_cant_overlap_transient_actions() {
+        let mut char = Character::from(CharacterId::CPO);
+        char.simple_inputs.insert(
+            "{6}g".into(),
+            ActionId::CPO(foundation::CPOAction::GroundTimeWinderStraight(
+                foundation::SpecialVersion::Fast,
+            )),
+        );
+
+        assert_eq!(
+            char.validate(),
+            vec![crate::ValidationError::ShortcutOverlap {
+                shortcut: "{6}g".into(),
+                action: ActionId::CPO(foundation::CPOAction::Jackpot),
+            }]
+        );
+    }
+
+    #[test]
+    fn all_characters_are_valid() {
+        for id in CharacterId::iter() {
+            let errors = Character::from(id).validate();
+            assert!(
+                errors.is_empty(),
+                "{id} has problems:\n{}",
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+        }
+    }
+
     fn contains_starter(req: &ActionRequirement) -> bool {
         match req {
             ActionRequirement::Starter(_) => true,
@@ -1,7 +1,12 @@
 mod character;
 mod cpo;
 mod ronin;
+mod validation;
 
-pub use character::Character;
+pub(crate) use character::BaseCharacter;
+pub use character::{Character, Palette};
 pub use cpo::cpo;
+pub(crate) use cpo::cpo_base;
 pub use ronin::ronin;
+pub(crate) use ronin::ronin_base;
+pub use validation::ValidationError;
@@ -0,0 +1,297 @@
+use bevy::{platform::collections::HashSet, prelude::*};
+use foundation::{ActionId, Animation, CancelType, ItemId, Stats, StatusFlag};
+
+use crate::{
+    ActionEvent, ActionRequirement, ActionTracker, GaugeType, Gauges, HitInfo, Inventory,
+    ItemCategory, Situation,
+};
+
+use super::Character;
+
+// Longest anything is simulated for, moves that haven't ended by now are considered stuck
+const SIMULATED_FRAMES: usize = 600;
+
+/// Mistakes in character data that the compiler can't catch
+/// Inputs and animation clips live in other crates, so they are checked there
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    NoEnd(ActionId),
+    MissingAction {
+        source: ActionId,
+        target: ActionId,
+    },
+    MissingItem {
+        source: ActionId,
+        item: ItemId,
+    },
+    MissingGauge {
+        source: ActionId,
+        gauge: GaugeType,
+    },
+    MissingComponent {
+        item: ItemId,
+        component: ItemId,
+    },
+    /// Transient actions run on top of anything, so the shortcut would fire both
+    ShortcutOverlap {
+        shortcut: String,
+        action: ActionId,
+    },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::NoEnd(id) => write!(f, "{id:?} never ends"),
+            ValidationError::MissingAction { source, target } => {
+                write!(f, "{source:?} refers to {target:?}, which doesn't exist")
+            }
+            ValidationError::MissingItem { source, item } => {
+                write!(f, "{source:?} requires {item:?}, which isn't sold")
+            }
+            ValidationError::MissingGauge { source, gauge } => {
+                write!(
+                    f,
+                    "{source:?} uses {gauge:?}, which the character doesn't have"
+                )
+            }
+            ValidationError::MissingComponent { item, component } => {
+                write!(f, "{item:?} is built from {component:?}, which isn't sold")
+            }
+            ValidationError::ShortcutOverlap { shortcut, action } => {
+                write!(f, "Shortcut {shortcut} also triggers {action:?}")
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct References {
+    actions: HashSet<ActionId>,
+    items: HashSet<ItemId>,
+    gauges: HashSet<GaugeType>,
+    animations: HashSet<Animation>,
+}
+
+impl References {
+    fn add_requirement(&mut self, requirement: &ActionRequirement) {
+        match requirement {
+            ActionRequirement::ActionOngoing(ids) | ActionRequirement::ActionNotOngoing(ids) => {
+                self.actions.extend(ids.iter().copied());
+            }
+            ActionRequirement::ItemOwned(item) => {
+                self.items.insert(*item);
+            }
+            ActionRequirement::ResourceFull(gauge) | ActionRequirement::ResourceValue(gauge, _) => {
+                self.gauges.insert(*gauge);
+            }
+            ActionRequirement::And(inner) | ActionRequirement::Or(inner) => {
+                for requirement in inner {
+                    self.add_requirement(requirement);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add_events(&mut self, events: &[ActionEvent], situation: &Situation) {
+        for event in events {
+            match event {
+                ActionEvent::StartAction(id) | ActionEvent::CommandPuppets(id) => {
+                    self.actions.insert(*id);
+                }
+                ActionEvent::Animation(request) => {
+                    self.animations.insert(request.animation);
+                }
+                ActionEvent::ModifyResource(gauge, _) | ActionEvent::ClearResource(gauge) => {
+                    self.gauges.insert(*gauge);
+                }
+                ActionEvent::Condition(condition) => {
+                    if let StatusFlag::Cancel(CancelType::Specific(ids)) = &condition.flag {
+                        self.actions.extend(ids.iter().copied());
+                    }
+                }
+                ActionEvent::SpawnHitbox(attack) => {
+                    // Blocked and hit both, as they can cancel to different things
+                    for avoided in [false, true] {
+                        let effect = (attack.on_hit)(
+                            situation,
+                            &HitInfo {
+                                avoided,
+                                airborne: false,
+                                hitbox_pos: Vec2::ZERO,
+                                defender_stats: Stats::character_default(),
+                                counter: None,
+                            },
+                        );
+                        self.add_events(&effect.attacker, situation);
+                        self.add_events(&effect.defender, situation);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Character {
+    fn situation(&self, action_id: ActionId, char_frame: usize) -> Situation {
+        Situation {
+            tracker: Some(ActionTracker {
+                start_frame: 0,
+                action_id,
+                ..default()
+            }),
+            resources: Gauges::from_stats(&self.base_stats, self.special_properties.clone()).0,
+            inventory: Inventory::default(),
+            stats: self.base_stats,
+            grounded: true,
+            char_frame,
+            ..default()
+        }
+    }
+
+    /// Scripts branch on these, so each branch gets a look
+    fn situation_variants(&self, action_id: ActionId, char_frame: usize) -> [Situation; 4] {
+        let grounded = self.situation(action_id, char_frame);
+
+        let mut full_gauges = grounded.clone();
+        for (_, gauge) in &mut full_gauges.resources {
+            gauge.current = gauge.max.unwrap_or(gauge.current);
+        }
+
+        let inventory = Inventory {
+            items: self
+                .items
+                .iter()
+                .map(|(id, item)| (*id, item.max_stack))
+                .collect(),
+            ..default()
+        };
+        let all_items = Situation {
+            stats: self.base_stats.combine(&inventory.get_effects(self)),
+            inventory,
+            ..grounded.clone()
+        };
+
+        [
+            Situation {
+                grounded: false,
+                ..grounded.clone()
+            },
+            full_gauges,
+            all_items,
+            grounded,
+        ]
+    }
+
+    fn references(&self, action_id: ActionId) -> References {
+        let action = &self.moves[&action_id];
+        let mut references = References::default();
+        references.add_requirement(&action.requirement);
+
+        for frame in 0..=SIMULATED_FRAMES {
+            for situation in self.situation_variants(action_id, frame) {
+                let events = (action.script)(&situation);
+                references.add_events(&events, &situation);
+            }
+            if action.transient {
+                break;
+            }
+        }
+
+        references
+    }
+
+    /// Every animation this character may play
+    pub fn animations(&self) -> HashSet<Animation> {
+        self.moves
+            .keys()
+            .flat_map(|id| self.references(*id).animations)
+            .chain(self.generic_animations.values().copied())
+            .collect()
+    }
+
+    /// Checks the moves, items and gauges refer to each other correctly
+    /// References are gathered grounded, airborne, with full gauges and with every item owned,
+    /// but moves only need to end when grounded
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let gauges: HashSet<GaugeType> =
+            Gauges::from_stats(&self.base_stats, self.special_properties.clone())
+                .iter()
+                .map(|(gauge, _)| *gauge)
+                .collect();
+
+        let mut errors = vec![];
+        for (id, action) in &self.moves {
+            if !action.transient {
+                let ends = (action.script)(&self.situation(*id, SIMULATED_FRAMES))
+                    .iter()
+                    .any(|ev| matches!(ev, ActionEvent::End | ActionEvent::StartAction(_)));
+
+                if !ends {
+                    errors.push(ValidationError::NoEnd(*id));
+                }
+            }
+
+            let references = self.references(*id);
+            errors.extend(
+                references
+                    .actions
+                    .into_iter()
+                    .filter(|target| !self.moves.contains_key(target))
+                    .map(|target| ValidationError::MissingAction {
+                        source: *id,
+                        target,
+                    }),
+            );
+            errors.extend(
+                references
+                    .items
+                    .into_iter()
+                    .filter(|item| !self.items.contains_key(item))
+                    .map(|item| ValidationError::MissingItem { source: *id, item }),
+            );
+            errors.extend(
+                references
+                    .gauges
+                    .into_iter()
+                    .filter(|gauge| !gauges.contains(gauge))
+                    .map(|gauge| ValidationError::MissingGauge { source: *id, gauge }),
+            );
+        }
+
+        for (id, item) in &self.items {
+            if let ItemCategory::Upgrade(components) = &item.category {
+                errors.extend(
+                    components
+                        .iter()
+                        .filter(|component| !self.items.contains_key(*component))
+                        .map(|component| ValidationError::MissingComponent {
+                            item: *id,
+                            component: *component,
+                        }),
+                );
+            }
+        }
+
+        for shortcut in self.simple_inputs.keys() {
+            errors.extend(
+                self.moves
+                    .iter()
+                    .filter(|(_, action)| {
+                        action.transient
+                            && action.input.as_ref().is_some_and(|input| {
+                                input.chars().count() == 1 && shortcut.ends_with(input.as_str())
+                            })
+                    })
+                    .map(|(id, _)| ValidationError::ShortcutOverlap {
+                        shortcut: shortcut.clone(),
+                        action: *id,
+                    }),
+            );
+        }
+
+        errors
+    }
+}
@@ -0,0 +1,720 @@
+use std::sync::Arc;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use foundation::{
+    ActionId, Animation, AnimationType, Area, Armor, CancelType, CharacterId, GameButton, ItemId,
+    Model, ProjectileGuard, Sound, Stats, StatusCondition, StatusFlag, StickPosition, VfxRequest,
+    VisualEffect, VoiceLine,
+};
+
+use crate::{
+    characters::{cpo_base, ronin_base, BaseCharacter},
+    jumps, Action, ActionBuilder, ActionEvent, ActionRequirement, AttackBuilder, AttackHeight,
+    Character, CharacterBoxes, CharacterUniversals, ChargeProperty, CounterBonus, DashBuilder,
+    Gauge, GaugeType, HitBuilder, Item, ItemCatalog, Movement, Palette, ProjectileBehaviour,
+    RenderInstructions, Situation, SpecialProperty, Stun, ThrowEffectBuilder,
+};
+
+/// Everything about a character that can be described without code
+/// Moves that need scripting (stance branching, projectiles, items) come from the base character
+/// Items themselves are described in an ItemCatalog
+#[derive(Debug, Clone, Serialize, Deserialize, Asset, TypePath)]
+pub struct CharacterDefinition {
+    pub base: CharacterId,
+    pub model: Model,
+    pub theme_song: Sound,
+    /// Selectable in character select, the first one is the default
+    pub palettes: Vec<Palette>,
+    pub generic_animations: Vec<(AnimationType, Animation)>,
+    pub jump: JumpDefinition,
+    pub stats: Stats,
+    pub boxes: CharacterBoxes,
+    #[serde(default)]
+    pub gauges: Vec<GaugeDefinition>,
+    #[serde(default)]
+    pub voicelines: Vec<(VoiceLine, Sound)>,
+    #[serde(default)]
+    pub simple_inputs: Vec<(String, ActionId)>,
+    #[serde(default)]
+    pub dashes: Vec<DashDefinition>,
+    #[serde(default)]
+    pub moves: Vec<MoveDefinition>,
+    #[serde(default)]
+    pub throw_effects: Vec<ThrowEffectDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JumpDefinition {
+    pub height: f32,
+    /// Seconds
+    pub duration: f32,
+    pub animation: Animation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GaugeDefinition {
+    pub gauge: GaugeType,
+    #[serde(default)]
+    pub max: Option<i32>,
+    pub render: RenderInstructions,
+    #[serde(default)]
+    pub charge: Option<ChargeDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChargeDefinition {
+    pub directions: Vec<StickPosition>,
+    #[serde(default)]
+    pub buttons: Vec<GameButton>,
+}
+
+/// Builds the plain and the metered version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashDefinition {
+    #[serde(default)]
+    pub back: bool,
+    #[serde(default)]
+    pub air: bool,
+    pub animation: Animation,
+    pub duration: usize,
+    /// Written as if going forward, back dashes flip these
+    pub movements: Vec<(usize, Movement)>,
+}
+
+/// What happens to both sides once a throw connects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThrowEffectDefinition {
+    /// Throws can share the thrower side, so it's optional
+    #[serde(default)]
+    pub hit: Option<ActionId>,
+    pub target: ActionId,
+    pub hit_animation: Animation,
+    pub hit_duration: usize,
+    pub target_animation: Animation,
+    pub target_duration: usize,
+    pub damage: i32,
+    /// Positive x sends the target backwards
+    pub launch: Vec2,
+    #[serde(default)]
+    pub target_events: Vec<EventDefinition>,
+    /// Can only be shorter than the default
+    #[serde(default)]
+    pub tech_window: Option<usize>,
+    #[serde(default)]
+    pub untechable: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum Stance {
+    #[default]
+    Standing,
+    Crouching,
+    Airborne,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveDefinition {
+    pub id: ActionId,
+    /// Normals are bound to a button, specials to an input
+    #[serde(default)]
+    pub button: Option<GameButton>,
+    #[serde(default)]
+    pub input: Option<String>,
+    #[serde(default)]
+    pub input_buffer: Option<usize>,
+    #[serde(default)]
+    pub special: bool,
+    #[serde(default)]
+    pub meter_cost: bool,
+    #[serde(default)]
+    pub stance: Stance,
+    /// Grunts and smear colors of the base character
+    #[serde(default = "yes")]
+    pub universals: bool,
+    #[serde(default)]
+    pub follow_up_from: Vec<ActionId>,
+    #[serde(default)]
+    pub requires_item: Option<ItemId>,
+    #[serde(default)]
+    pub animation: Option<Animation>,
+    #[serde(default)]
+    pub sound: Option<Sound>,
+    pub duration: usize,
+    #[serde(default)]
+    pub initial_events: Vec<EventDefinition>,
+    #[serde(default)]
+    pub events: Vec<(usize, Vec<EventDefinition>)>,
+    /// Sent on every frame after the given one
+    #[serde(default)]
+    pub events_after: Vec<(usize, Vec<EventDefinition>)>,
+    #[serde(default)]
+    pub vfx: Vec<VfxDefinition>,
+    #[serde(default)]
+    pub armor: Option<ArmorDefinition>,
+    #[serde(default)]
+    pub projectile_guard: Option<ProjectileGuardDefinition>,
+    /// Stance follow ups like the cancel don't hit at all
+    #[serde(default)]
+    pub hits: Vec<HitDefinition>,
+}
+
+/// Mirrored to match the direction the character is facing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VfxDefinition {
+    pub frame: usize,
+    pub effect: VisualEffect,
+    pub translation: Vec3,
+    /// Euler angles in ZYX order
+    #[serde(default)]
+    pub rotation: Vec3,
+    pub scale: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArmorDefinition {
+    pub first_frame: usize,
+    pub last_frame: usize,
+    pub armor: Armor,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectileGuardDefinition {
+    pub first_frame: usize,
+    pub last_frame: usize,
+    pub guard: ProjectileGuard,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HitDefinition {
+    pub frame: usize,
+    pub active_frames: usize,
+    pub hitbox: Area,
+    #[serde(default)]
+    pub damage: Option<i32>,
+    #[serde(default)]
+    pub on_block: Option<Stun>,
+    #[serde(default)]
+    pub on_hit: Option<Stun>,
+    #[serde(default)]
+    pub height: Option<AttackHeight>,
+    #[serde(default)]
+    pub sword: bool,
+    #[serde(default)]
+    pub knocks_down: bool,
+    #[serde(default)]
+    pub launch: Option<Vec2>,
+    #[serde(default)]
+    pub counter_hit: Option<CounterBonus>,
+    #[serde(default)]
+    pub punish: Option<CounterBonus>,
+    #[serde(default)]
+    pub juggle_cost: Option<usize>,
+    #[serde(default)]
+    pub skip_hitstun_decay: bool,
+    /// Either of these makes the hit a projectile
+    #[serde(default)]
+    pub behaviour: Option<ProjectileBehaviour>,
+    #[serde(default)]
+    pub durability: Option<usize>,
+    #[serde(default)]
+    pub distance_on_hit: Option<f32>,
+    #[serde(default)]
+    pub distance_on_block: Option<f32>,
+    #[serde(default)]
+    pub pushback_on_hit: Option<f32>,
+    #[serde(default)]
+    pub cancels: Option<(CancelType, usize)>,
+    /// Duration and control points
+    #[serde(default)]
+    pub smear: Option<(usize, Vec<Vec3>)>,
+    #[serde(default)]
+    pub events: Vec<EventDefinition>,
+    /// On hit events for when the attacker owns the item
+    #[serde(default)]
+    pub item_events: Vec<(ItemId, Vec<EventDefinition>)>,
+    /// Makes this a throw, strike options don't apply to those
+    #[serde(default)]
+    pub throw: Option<ThrowDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThrowDefinition {
+    /// Switches sides with the target
+    #[serde(default)]
+    pub back: bool,
+    pub hit_action: ActionId,
+    pub target_action: ActionId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EventDefinition {
+    Movement(Movement),
+    ExpandHurtbox(Area, usize),
+    KaraTo(Vec<ActionId>),
+    Teleport(Vec2),
+    MultiplyMomentum(Vec2),
+    Hitstop(usize),
+    Sound(Sound),
+    StartAction(ActionId),
+    ModifyResource(GaugeType, i32),
+    ClearCondition(StatusFlag),
+    /// Relative to the character, not mirrored
+    VisualEffect(VisualEffect, Vec3),
+}
+impl From<EventDefinition> for ActionEvent {
+    fn from(value: EventDefinition) -> Self {
+        match value {
+            EventDefinition::Movement(movement) => movement.into(),
+            EventDefinition::ExpandHurtbox(area, duration) => {
+                ActionEvent::ExpandHurtbox(area, duration)
+            }
+            EventDefinition::KaraTo(options) => {
+                ActionEvent::Condition(StatusCondition::kara_to(options))
+            }
+            EventDefinition::Teleport(offset) => ActionEvent::Teleport(offset),
+            EventDefinition::MultiplyMomentum(amount) => ActionEvent::MultiplyMomentum(amount),
+            EventDefinition::Hitstop(frames) => ActionEvent::Hitstop(frames),
+            EventDefinition::Sound(sound) => ActionEvent::Sound(sound.into()),
+            EventDefinition::StartAction(id) => ActionEvent::StartAction(id),
+            EventDefinition::ModifyResource(gauge, amount) => {
+                ActionEvent::ModifyResource(gauge, amount)
+            }
+            EventDefinition::ClearCondition(flag) => ActionEvent::ClearCondition(flag),
+            EventDefinition::VisualEffect(effect, translation) => VfxRequest {
+                effect,
+                tf: Transform::from_translation(translation),
+                ..default()
+            }
+            .into(),
+        }
+    }
+}
+
+fn yes() -> bool {
+    true
+}
+
+fn events(definitions: &[EventDefinition]) -> Vec<ActionEvent> {
+    definitions.iter().cloned().map(ActionEvent::from).collect()
+}
+
+impl HitDefinition {
+    fn builder(&self, special: bool) -> HitBuilder {
+        let mut hit = if special {
+            HitBuilder::special()
+        } else {
+            HitBuilder::normal()
+        };
+
+        if let Some(throw) = &self.throw {
+            hit = if throw.back {
+                hit.back_throw()
+            } else {
+                hit.forward_throw()
+            }
+            .throw_hit_action(throw.hit_action)
+            .throw_target_action(throw.target_action);
+        }
+
+        hit = hit
+            .with_active_frames(self.active_frames)
+            .with_hitbox(self.hitbox);
+
+        if let Some(damage) = self.damage {
+            hit = hit.with_damage(damage);
+        }
+        if let Some(stun) = self.on_block {
+            hit = hit.with_strike_builder(|sb| sb.with_blockstun(stun));
+        }
+        if let Some(stun) = self.on_hit {
+            hit = hit.with_strike_builder(|sb| sb.with_hitstun(stun));
+        }
+        if let Some(height) = self.height {
+            hit = hit.with_attack_height(height);
+        }
+        if self.sword {
+            hit = hit.sword();
+        }
+        if self.knocks_down {
+            hit = hit.knocks_down();
+        }
+        if let Some(bonus) = self.counter_hit {
+            hit = hit.with_counter_hit_bonus(bonus);
+        }
+        if let Some(bonus) = self.punish {
+            hit = hit.with_punish_bonus(bonus);
+        }
+        if let Some(cost) = self.juggle_cost {
+            hit = hit.with_juggle_cost(cost);
+        }
+        if self.skip_hitstun_decay {
+            hit = hit.without_hitstun_decay();
+        }
+        if let Some(behaviour) = self.behaviour {
+            hit = hit.with_behaviour(behaviour);
+        }
+        if let Some(durability) = self.durability {
+            hit = hit.with_durability(durability);
+        }
+        if let Some(distance) = self.distance_on_hit {
+            hit = hit.with_distance_on_hit(distance);
+        }
+        if let Some(distance) = self.distance_on_block {
+            hit = hit.with_distance_on_block(distance);
+        }
+        if let Some(amount) = self.pushback_on_hit {
+            hit = hit.with_pushback_on_hit(amount);
+        }
+        // Launches set their own defender pushback, so this goes after the distances
+        if let Some(impulse) = self.launch {
+            hit = hit.launches(impulse);
+        }
+        if let Some((cancel_type, window)) = self.cancels.clone() {
+            hit = hit.with_cancels_to(cancel_type, window);
+        }
+        if let Some((duration, control_points)) = self.smear.clone() {
+            hit = hit.with_smear(duration, control_points);
+        }
+        if !self.events.is_empty() {
+            hit = hit.with_additional_events(events(&self.events));
+        }
+        if !self.item_events.is_empty() {
+            let item_events: Vec<(ItemId, Vec<ActionEvent>)> = self
+                .item_events
+                .iter()
+                .map(|(item, definitions)| (*item, events(definitions)))
+                .collect();
+
+            hit = hit.with_dynamic_on_hit_events(Arc::new(move |situation: &Situation| {
+                item_events
+                    .iter()
+                    .filter(|(item, _)| situation.inventory.contains(*item))
+                    .flat_map(|(_, events)| events.clone())
+                    .collect()
+            }));
+        }
+
+        hit
+    }
+}
+
+impl VfxDefinition {
+    fn transform(&self) -> Transform {
+        Transform {
+            translation: self.translation,
+            rotation: Quat::from_euler(
+                EulerRot::ZYX,
+                self.rotation.x,
+                self.rotation.y,
+                self.rotation.z,
+            ),
+            scale: Vec3::splat(self.scale),
+        }
+    }
+}
+
+impl MoveDefinition {
+    fn build(&self, base: &BaseCharacter) -> Action {
+        let mut builder = match (self.special, self.button) {
+            (true, _) => ActionBuilder::special(),
+            (false, Some(button)) => ActionBuilder::button(button),
+            (false, None) => ActionBuilder::normal(),
+        };
+
+        builder = match self.stance {
+            Stance::Standing => builder,
+            Stance::Crouching => builder.crouching(),
+            Stance::Airborne => builder.air_only(),
+        };
+
+        if self.universals {
+            builder = builder.with_character_universals(base.universals);
+        }
+        if let Some(input) = &self.input {
+            builder = builder.with_input(input.clone());
+        }
+        if let Some(frames) = self.input_buffer {
+            builder = builder.with_input_buffer(frames);
+        }
+        if self.meter_cost {
+            builder = builder.with_meter_cost();
+        }
+        if !self.follow_up_from.is_empty() {
+            builder = builder.follow_up_from(self.follow_up_from.clone());
+        }
+        if let Some(item) = self.requires_item {
+            builder = builder.with_requirement(ActionRequirement::ItemOwned(item));
+        }
+        if let Some(animation) = self.animation {
+            builder = builder.with_animation(animation);
+        }
+        if let Some(sound) = self.sound {
+            builder = builder.with_sound(sound);
+        }
+
+        builder = builder.end_at(self.duration);
+
+        if !self.initial_events.is_empty() {
+            builder = builder.static_immediate_events(events(&self.initial_events));
+        }
+        for (frame, frame_events) in &self.events {
+            builder = builder.static_events_on_frame(*frame, events(frame_events));
+        }
+        for (frame, frame_events) in &self.events_after {
+            builder = builder.static_events_after_frame(*frame, events(frame_events));
+        }
+        for vfx in &self.vfx {
+            builder = builder.with_vfx_on_frame(vfx.frame, vfx.effect.clone(), vfx.transform());
+        }
+        if let Some(armor) = &self.armor {
+            builder = builder.with_armor(armor.first_frame, armor.last_frame, armor.armor);
+        }
+        if let Some(guard) = &self.projectile_guard {
+            builder =
+                builder.with_projectile_guard(guard.first_frame, guard.last_frame, guard.guard);
+        }
+
+        if self.hits.is_empty() {
+            return builder.build();
+        }
+
+        let mut attack = AttackBuilder::from(builder);
+        for hit in &self.hits {
+            attack = attack.with_hit_on_frame(hit.frame, hit.builder(self.special));
+        }
+
+        attack.build()
+    }
+}
+
+impl DashDefinition {
+    fn build(&self, universals: CharacterUniversals) -> impl Iterator<Item = (ActionId, Action)> {
+        let mut builder = if self.back {
+            DashBuilder::back()
+        } else {
+            DashBuilder::forward()
+        };
+        if self.air {
+            builder = builder.air_only();
+        }
+
+        builder = builder
+            .with_animation(self.animation)
+            .with_character_universals(universals)
+            .end_at(self.duration);
+
+        for (frame, movement) in &self.movements {
+            builder = builder.on_frame(*frame, *movement);
+        }
+
+        builder.build()
+    }
+}
+
+impl ThrowEffectDefinition {
+    fn build(&self) -> impl Iterator<Item = (ActionId, Action)> {
+        let mut builder = ThrowEffectBuilder::new(
+            self.hit_animation,
+            self.hit_duration,
+            self.target_animation,
+            self.target_duration,
+        )
+        .with_damage(self.damage)
+        .with_launch_impulse(self.launch)
+        .with_extra_target_events(events(&self.target_events));
+
+        if self.untechable {
+            builder = builder.untechable();
+        } else if let Some(frames) = self.tech_window {
+            builder = builder.with_tech_window(frames);
+        }
+
+        let (target, hit) = builder.build();
+
+        std::iter::once((self.target, target)).chain(self.hit.map(|id| (id, hit)))
+    }
+}
+
+impl GaugeDefinition {
+    fn build(&self) -> (GaugeType, Gauge) {
+        (
+            self.gauge,
+            Gauge {
+                max: self.max,
+                render_instructions: self.render.clone(),
+                special: self.charge.clone().map(|charge| {
+                    SpecialProperty::Charge(ChargeProperty {
+                        directions: charge.directions,
+                        buttons: charge.buttons,
+                        ..default()
+                    })
+                }),
+                ..default()
+            },
+        )
+    }
+}
+
+impl CharacterDefinition {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// Path under the assets folder
+    pub fn path(character: CharacterId) -> &'static str {
+        match character {
+            CharacterId::Ronin => "characters/ronin.character.ron",
+            CharacterId::CPO => "characters/cpo.character.ron",
+        }
+    }
+
+    /// The definition shipped with the game, used when the asset isn't available
+    pub fn embedded(character: CharacterId) -> Self {
+        let source = match character {
+            CharacterId::Ronin => {
+                include_str!("../../main/assets/characters/ronin.character.ron")
+            }
+            CharacterId::CPO => include_str!("../../main/assets/characters/cpo.character.ron"),
+        };
+
+        Self::from_ron(source)
+            .unwrap_or_else(|err| panic!("Embedded {character} definition is invalid: {err}"))
+    }
+
+    /// Items are sold as listed, handlers from the base character are attached to them
+    pub fn build(&self, items: &ItemCatalog) -> Character {
+        let base = match self.base {
+            CharacterId::Ronin => ronin_base(),
+            CharacterId::CPO => cpo_base(),
+        };
+
+        let (jumps, gravity) = jumps(self.jump.height, self.jump.duration, self.jump.animation);
+
+        let data_moves: Vec<(ActionId, Action)> = self
+            .dashes
+            .iter()
+            .flat_map(|definition| definition.build(base.universals))
+            .chain(
+                self.moves
+                    .iter()
+                    .map(|definition| (definition.id, definition.build(&base))),
+            )
+            .chain(
+                self.throw_effects
+                    .iter()
+                    .flat_map(ThrowEffectDefinition::build),
+            )
+            .collect();
+
+        let moves: HashMap<ActionId, Action> = jumps.chain(base.moves).chain(data_moves).collect();
+
+        let mut items: HashMap<ItemId, Item> = items.items.iter().cloned().collect();
+        for (id, handler) in base.item_handlers {
+            if let Some(item) = items.get_mut(&id) {
+                item.handlers.push(handler);
+            }
+        }
+
+        Character::new(
+            self.model,
+            self.theme_song,
+            self.palettes.clone(),
+            self.generic_animations.iter().copied().collect(),
+            moves,
+            items,
+            self.boxes,
+            Stats {
+                gravity,
+                ..self.stats
+            },
+            self.gauges.iter().map(GaugeDefinition::build).collect(),
+            self.voicelines.iter().copied().collect(),
+            self.simple_inputs.iter().cloned().collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use foundation::{CPOAction, RoninAction};
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::ActionTracker;
+
+    #[test]
+    fn embedded_definitions_build() {
+        for id in CharacterId::iter() {
+            let definition = CharacterDefinition::embedded(id);
+            assert_eq!(definition.base, id);
+
+            let character = definition.build(&ItemCatalog::embedded_for(id));
+            for data_move in &definition.moves {
+                assert!(character.moves.contains_key(&data_move.id));
+            }
+            for action in definition.simple_inputs.iter().map(|(_, action)| action) {
+                assert!(character.moves.contains_key(action));
+            }
+        }
+    }
+
+    #[test]
+    fn definitions_survive_serialization() {
+        for id in CharacterId::iter() {
+            let definition = CharacterDefinition::embedded(id);
+            let serialized = ron::to_string(&definition).unwrap();
+            let parsed = CharacterDefinition::from_ron(&serialized).unwrap();
+            assert_eq!(ron::to_string(&parsed).unwrap(), serialized, "{id}");
+        }
+    }
+
+    /// First frame the move spawns a hitbox on and the first frame past its duration
+    fn frame_data(character: &Character, action_id: ActionId) -> (Option<usize>, Option<usize>) {
+        let action = &character.moves[&action_id];
+        let mut startup = None;
+
+        for char_frame in 0..200 {
+            let situation = Situation {
+                tracker: Some(ActionTracker {
+                    action_id,
+                    ..default()
+                }),
+                stats: Stats::character_default(),
+                grounded: true,
+                char_frame,
+                ..default()
+            };
+
+            for event in (action.script)(&situation) {
+                match event {
+                    ActionEvent::SpawnHitbox(_) => {
+                        startup.get_or_insert(char_frame);
+                    }
+                    ActionEvent::End => return (startup, Some(char_frame)),
+                    _ => {}
+                }
+            }
+        }
+
+        (startup, None)
+    }
+
+    #[test]
+    fn key_move_frame_data() {
+        let ronin = CharacterDefinition::embedded(CharacterId::Ronin)
+            .build(&ItemCatalog::embedded_for(CharacterId::Ronin));
+        assert_eq!(
+            frame_data(&ronin, RoninAction::Uppercut.into()),
+            (Some(8), Some(49))
+        );
+
+        let cpo = CharacterDefinition::embedded(CharacterId::CPO)
+            .build(&ItemCatalog::embedded_for(CharacterId::CPO));
+        assert_eq!(
+            frame_data(&cpo, CPOAction::Chop.into()),
+            (Some(6), Some(17))
+        );
+    }
+}
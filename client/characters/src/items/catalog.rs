@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use foundation::{CharacterId, ItemId};
+
+use super::Item;
+
+/// Items as described in the item files, handlers get attached when the character is built
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Asset, TypePath)]
+pub struct ItemCatalog {
+    pub items: Vec<(ItemId, Item)>,
+}
+
+impl ItemCatalog {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// Path under the assets folder, None is for the items everyone can buy
+    pub fn path(character: Option<CharacterId>) -> &'static str {
+        match character {
+            None => "items/universal.items.ron",
+            Some(CharacterId::Ronin) => "items/ronin.items.ron",
+            Some(CharacterId::CPO) => "items/cpo.items.ron",
+        }
+    }
+
+    /// The catalog shipped with the game, used when the asset isn't available
+    pub fn embedded(character: Option<CharacterId>) -> Self {
+        let source = match character {
+            None => include_str!("../../../main/assets/items/universal.items.ron"),
+            Some(CharacterId::Ronin) => {
+                include_str!("../../../main/assets/items/ronin.items.ron")
+            }
+            Some(CharacterId::CPO) => include_str!("../../../main/assets/items/cpo.items.ron"),
+        };
+
+        Self::from_ron(source)
+            .unwrap_or_else(|err| panic!("Embedded {} is invalid: {err}", Self::path(character)))
+    }
+
+    /// Everything the character can buy, using the embedded catalogs
+    pub fn embedded_for(character: CharacterId) -> Self {
+        Self::embedded(None).combined(&Self::embedded(Some(character)))
+    }
+
+    pub fn combined(&self, other: &Self) -> Self {
+        Self {
+            items: self.items.iter().chain(&other.items).cloned().collect(),
+        }
+    }
+
+    /// Only one of these survives when the character is built
+    pub fn duplicates(&self) -> Vec<ItemId> {
+        let mut seen = vec![];
+        let mut duplicates = vec![];
+
+        for (id, _) in &self.items {
+            if seen.contains(id) {
+                duplicates.push(*id);
+            } else {
+                seen.push(*id);
+            }
+        }
+
+        duplicates
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn every_item_is_sold_once() {
+        for character in CharacterId::iter() {
+            assert_eq!(ItemCatalog::embedded_for(character).duplicates(), vec![]);
+        }
+
+        let catalogs: Vec<ItemCatalog> = [None]
+            .into_iter()
+            .chain(CharacterId::iter().map(Some))
+            .map(ItemCatalog::embedded)
+            .collect();
+
+        for id in ItemId::iter() {
+            assert!(
+                catalogs
+                    .iter()
+                    .any(|catalog| catalog.items.iter().any(|(item, _)| *item == id)),
+                "{id:?} isn't in any item file"
+            );
+        }
+    }
+
+    #[test]
+    fn left_out_fields_use_defaults() {
+        let catalog = ItemCatalog::from_ron(
+            "(items: [(Boots, (name: \"Boots\", cost: 10, effect: (walk_speed: 1.0)))])",
+        )
+        .unwrap();
+
+        let (id, item) = &catalog.items[0];
+        assert_eq!(*id, ItemId::Boots);
+        assert_eq!(item.max_stack, 1);
+        assert_eq!(item.effect.walk_speed, 1.0);
+        assert_eq!(item.effect.max_health, 0);
+    }
+}
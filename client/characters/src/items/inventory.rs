@@ -1,7 +1,7 @@
 use bevy::{platform::collections::HashMap, prelude::*};
-use foundation::{ItemId, Stats, SELL_RETURN, STARTING_MONEY};
+use foundation::{ItemId, MatchRules, Stats};
 
-use crate::{Character, ConsumableType, Item, ItemCategory};
+use crate::{ActionEvent, Character, ConsumableType, Item, ItemCategory, ItemTrigger, Situation};
 
 #[derive(Debug, Component, Eq, PartialEq, Reflect, Clone)]
 pub struct Inventory {
@@ -13,7 +13,7 @@ pub struct Inventory {
 impl Default for Inventory {
     fn default() -> Self {
         Self {
-            money: STARTING_MONEY,
+            money: MatchRules::default().starting_money,
             items: HashMap::new(),
         }
     }
@@ -28,7 +28,7 @@ impl Inventory {
     }
 
     pub fn can_buy(&self, id: ItemId, item: &Item) -> bool {
-        if item.cost > self.money {
+        if item.banned || item.cost > self.money {
             return false;
         }
 
@@ -53,10 +53,10 @@ impl Inventory {
         *count += 1;
     }
 
-    pub fn sell(&mut self, character: &Character, id: ItemId) {
+    pub fn sell(&mut self, character: &Character, id: ItemId, rules: &MatchRules) {
         // Remove done by id since inventory can contain duplicates
         let item = character.items.get(&id).unwrap();
-        self.money += (item.cost as f32 * SELL_RETURN) as usize;
+        self.money += rules.sell_value(item.cost);
         let count = self.items.get_mut(&id).unwrap();
         if *count > 1 {
             *count -= 1;
@@ -69,12 +69,18 @@ impl Inventory {
             let owned_item = character.items.get(owned_id).unwrap();
             if let ItemCategory::Upgrade(components) = &owned_item.category {
                 if components.contains(&id) {
-                    self.sell(character, *owned_id);
+                    self.sell(character, *owned_id, rules);
                 }
             }
         }
     }
 
+    /// Undoes a purchase, for the full price
+    pub fn refund(&mut self, id: ItemId, item: &Item) {
+        self.money += item.cost;
+        self.consume(id);
+    }
+
     pub fn sell_price(&self, character: &Character, id: ItemId) -> usize {
         let item = character.items.get(&id).unwrap();
 
@@ -95,25 +101,172 @@ impl Inventory {
                 .sum::<usize>()
     }
 
+    /// Consumables have one charge per copy, the item is gone after the last one
+    pub fn consume(&mut self, id: ItemId) {
+        let Some(count) = self.items.get_mut(&id) else {
+            return;
+        };
+
+        *count -= 1;
+        if *count == 0 {
+            self.items.remove(&id);
+        }
+    }
+
     pub fn remove(&mut self, id: ItemId) {
         self.items.remove(&id);
     }
 
+    /// Items the character doesn't have (anymore, after a reload) are skipped
     pub fn get_effects(&self, character: &Character) -> Stats {
         self.items
             .iter()
-            .fold(Stats::default(), |accumulator, (id, count)| {
-                accumulator.combine(&character.items[id].effect.multiply(*count))
+            .filter_map(|(id, count)| Some((character.items.get(id)?, count)))
+            .fold(Stats::default(), |accumulator, (item, count)| {
+                accumulator.combine(&item.effect.multiply(*count))
+            })
+    }
+
+    /// Effects after buying one more of the item
+    pub fn effects_with(&self, character: &Character, id: ItemId) -> Stats {
+        let mut after = self.clone();
+        *after.items.entry(id).or_insert(0) += 1;
+        after.get_effects(character)
+    }
+
+    /// Stacked items fire once per copy
+    pub fn triggered_events(
+        &self,
+        character: &Character,
+        trigger: ItemTrigger,
+        situation: &Situation,
+    ) -> Vec<ActionEvent> {
+        self.items
+            .iter()
+            .filter_map(|(id, count)| Some((character.items.get(id)?, count)))
+            .flat_map(|(item, count)| {
+                item.handlers
+                    .iter()
+                    .filter(|handler| handler.trigger == trigger)
+                    .flat_map(move |handler| (0..*count).flat_map(|_| (handler.script)(situation)))
             })
+            .collect()
     }
 
     // Not a great name I'll admit
     pub fn remove_one_round_consumables(&mut self, character: &Character) {
         self.items.retain(|item, _| {
-            !matches!(
-                character.items.get(item).unwrap().category,
-                ItemCategory::Consumable(ConsumableType::OneRound)
-            )
+            !character.items.get(item).is_some_and(|item| {
+                matches!(
+                    item.category,
+                    ItemCategory::Consumable(ConsumableType::OneRound)
+                )
+            })
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use foundation::CharacterId;
+
+    use super::*;
+
+    #[test]
+    fn stacked_items_trigger_per_copy() {
+        let character = Character::from(CharacterId::Ronin);
+        let mut inventory = Inventory::default();
+        let situation = Situation::default();
+
+        assert!(inventory
+            .triggered_events(&character, ItemTrigger::ComboEnd, &situation)
+            .is_empty());
+
+        inventory.items.insert(ItemId::SafetyBoots, 2);
+        assert_eq!(
+            inventory
+                .triggered_events(&character, ItemTrigger::ComboEnd, &situation)
+                .len(),
+            2
+        );
+        assert!(inventory
+            .triggered_events(&character, ItemTrigger::Hit, &situation)
+            .is_empty());
+    }
+
+    #[test]
+    fn consuming_uses_charges() {
+        let mut inventory = Inventory::default();
+        inventory.items.insert(ItemId::SmokePellet, 2);
+
+        inventory.consume(ItemId::SmokePellet);
+        assert_eq!(inventory.count(ItemId::SmokePellet), 1);
+
+        inventory.consume(ItemId::SmokePellet);
+        assert!(!inventory.contains(ItemId::SmokePellet));
+
+        // Nothing left to use
+        inventory.consume(ItemId::SmokePellet);
+        assert!(!inventory.contains(ItemId::SmokePellet));
+    }
+
+    #[test]
+    fn previewing_effects() {
+        let character = Character::from(CharacterId::Ronin);
+        let mut inventory = Inventory::default();
+        inventory.items.insert(ItemId::ThumbTacks, 1);
+
+        let after = inventory.effects_with(&character, ItemId::ThumbTacks);
+        inventory.items.insert(ItemId::ThumbTacks, 2);
+        assert_eq!(after, inventory.get_effects(&character));
+    }
+
+    #[test]
+    fn items_dropped_by_reload_are_skipped() {
+        let mut character = Character::from(CharacterId::Ronin);
+        let mut inventory = Inventory::default();
+        inventory.items.insert(ItemId::SafetyBoots, 1);
+        inventory.items.insert(ItemId::Boots, 1);
+
+        character.items.remove(&ItemId::SafetyBoots);
+        assert!(inventory
+            .triggered_events(&character, ItemTrigger::ComboEnd, &Situation::default())
+            .is_empty());
+        assert_eq!(
+            inventory.get_effects(&character),
+            Stats::default().combine(&character.items[&ItemId::Boots].effect)
+        );
+    }
+
+    #[test]
+    fn banned_items_cant_be_bought() {
+        let mut character = Character::from(CharacterId::Ronin);
+        let inventory = Inventory {
+            money: 10000,
+            ..default()
+        };
+        assert!(inventory.can_buy(ItemId::Boots, &character.items[&ItemId::Boots]));
+
+        character.ban_items(&MatchRules {
+            banned_items: vec![ItemId::Boots],
+            ..default()
+        });
+        assert!(!inventory.can_buy(ItemId::Boots, &character.items[&ItemId::Boots]));
+        assert!(inventory.can_buy(ItemId::Gi, &character.items[&ItemId::Gi]));
+    }
+
+    #[test]
+    fn refunds_are_full() {
+        let character = Character::from(CharacterId::Ronin);
+        let item = character.items[&ItemId::Boots].clone();
+        let mut inventory = Inventory {
+            money: item.cost,
+            ..default()
+        };
+        let before = inventory.clone();
+
+        inventory.buy(ItemId::Boots, item.clone());
+        inventory.refund(ItemId::Boots, &item);
+        assert_eq!(inventory, before);
+    }
+}
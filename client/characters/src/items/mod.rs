@@ -1,46 +1,120 @@
+mod catalog;
 mod inventory;
 mod universal_items;
 
+pub use catalog::ItemCatalog;
 pub use inventory::Inventory;
-pub use universal_items::{universal_item_actions, universal_items};
+pub use universal_items::{universal_item_actions, universal_item_handlers};
 
-use foundation::{Icon, ItemId, Stats};
+use std::sync::Arc;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use foundation::{EffectStats, Icon, ItemId, ItemKind, Stats};
+
+use crate::{ActionEvent, Situation};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ConsumableType {
     OneRound,
     UntilUsed,
 }
 
-#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ItemCategory {
     Consumable(ConsumableType),
     #[default]
     Basic,
     Upgrade(Vec<ItemId>),
 }
+impl ItemCategory {
+    pub fn kind(&self) -> ItemKind {
+        match self {
+            ItemCategory::Consumable(_) => ItemKind::Consumable,
+            ItemCategory::Basic => ItemKind::Basic,
+            ItemCategory::Upgrade(_) => ItemKind::Upgrade,
+        }
+    }
+}
+
+/// Gameplay events items can react to, targeted at the player who the event happened to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event)]
+pub enum ItemTrigger {
+    /// Landed a hit or a throw
+    Hit,
+    /// First hit of a combo, comes after Hit
+    Opener,
+    Block,
+    Parry,
+    RoundStart,
+    /// Own combo dropped or finished
+    ComboEnd,
+}
+
+pub type ItemScript = Arc<dyn Fn(&Situation) -> Vec<ActionEvent> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct ItemHandler {
+    pub trigger: ItemTrigger,
+    /// Situation is that of the owner
+    pub script: ItemScript,
+}
+
+impl ItemHandler {
+    pub fn new(
+        trigger: ItemTrigger,
+        script: impl Fn(&Situation) -> Vec<ActionEvent> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            trigger,
+            script: Arc::new(script),
+        }
+    }
+}
+
+impl std::fmt::Debug for ItemHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ItemHandler")
+            .field("trigger", &self.trigger)
+            .finish()
+    }
+}
 
-#[derive(Debug, Clone, PartialEq)]
+/// Everything but the handlers comes from the item files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Item {
+    pub name: String,
     pub category: ItemCategory,
     pub cost: usize,
+    #[serde(deserialize_with = "EffectStats::deserialize")]
     pub effect: Stats,
+    /// Reactive effects on top of the static ones
+    #[serde(skip)]
+    pub handlers: Vec<ItemHandler>,
     pub explanation: String,
     pub icon: Icon,
     pub max_stack: usize,
     pub suggested: bool,
+    /// Set from the match rules when the character is built
+    #[serde(skip)]
+    pub banned: bool,
 }
 
 impl Default for Item {
     fn default() -> Self {
         Self {
+            name: "Name missing".into(),
             category: Default::default(),
             cost: Default::default(),
             effect: Default::default(),
+            handlers: vec![],
             explanation: "Description missing".into(),
             icon: Icon::Blank,
             max_stack: 1,
             suggested: false,
+            banned: false,
         }
     }
 }
@@ -4,6 +4,7 @@ mod actions;
 mod bridging;
 mod builders;
 mod characters;
+mod definition;
 mod items;
 mod resources;
 
@@ -12,11 +13,16 @@ pub(crate) use builders::*;
 
 pub use actions::{
     Action, ActionEvent, ActionRequirement, AnimationRequest, Attack, AttackHeight, BlockType,
-    CharacterStateBoxes, FlashRequest, Hitbox, Hurtboxes, Lifetime, Movement, ToHit,
+    CharacterStateBoxes, FlashRequest, Hitbox, Hurtboxes, Lifetime, Movement, ProjectileBehaviour,
+    ToHit,
+};
+pub use bridging::{ActionTracker, CounterHit, HitEffect, HitInfo, Situation};
+pub use characters::{cpo, ronin, Character, Palette, ValidationError};
+pub use definition::CharacterDefinition;
+pub use items::{
+    ConsumableType, Inventory, Item, ItemCatalog, ItemCategory, ItemHandler, ItemScript,
+    ItemTrigger,
 };
-pub use bridging::{ActionTracker, HitEffect, HitInfo, Situation};
-pub use characters::{cpo, ronin, Character};
-pub use items::{ConsumableType, Inventory, Item, ItemCategory};
 pub use resources::{
     ChargeProperty, CounterVisual, Gauge, GaugeType, Gauges, RenderInstructions, ResourceBarVisual,
     SpecialProperty,
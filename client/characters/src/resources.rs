@@ -1,11 +1,12 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use foundation::{
     GameButton, Stats, StickPosition, HEALTH_BAR_COLOR, METER_BAR_FULL_SEGMENT_COLOR,
     METER_BAR_PARTIAL_SEGMENT_COLOR, METER_BAR_SEGMENT,
 };
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Reflect)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Reflect, Serialize, Deserialize)]
 /// This is a quick handle that can be referred to in requirement checks
 pub enum GaugeType {
     Health,
@@ -93,7 +94,7 @@ impl Gauges {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RenderInstructions {
     Bar(ResourceBarVisual),
     Counter(CounterVisual),
@@ -167,7 +168,7 @@ impl ChargePerfection {
     }
 }
 
-#[derive(Debug, Clone, Copy, Component)]
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
 pub struct ResourceBarVisual {
     pub height: f32,
     pub default_color: Color,
@@ -210,9 +211,9 @@ impl ResourceBarVisual {
     }
 }
 
-#[derive(Debug, Clone, Copy, Component)]
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct CounterVisual {
-    pub label: &'static str,
+    pub label: String,
 }
 
 #[derive(Debug, Clone)]
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Reflect, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Reflect, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
 pub enum SpecialVersion {
     Metered,
     Strong,
@@ -8,7 +11,21 @@ pub enum SpecialVersion {
 }
 
 // NOTE: Order matters, later actions take priority.
-#[derive(Reflect, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Component)]
+#[derive(
+    Reflect,
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    Component,
+    Serialize,
+    Deserialize,
+)]
 pub enum ActionId {
     #[default]
     Default, // TODO: Get rid of this
@@ -30,6 +47,7 @@ pub enum ActionId {
     FastFall,
     GiParry,
     RomaineCancel,
+    SmokePellet,
     MeteredForwardDash,
     MeteredBackDash,
     AirForwardDash,
@@ -45,7 +63,9 @@ pub enum ActionId {
     CPO(CPOAction),
 }
 
-#[derive(Reflect, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Reflect, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
 pub enum RoninAction {
     SwordStance(SpecialVersion),
     StanceCancel(SpecialVersion),
@@ -91,7 +111,9 @@ impl From<RoninAction> for ActionId {
     }
 }
 
-#[derive(Reflect, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Reflect, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
 pub enum CPOAction {
     Jackpot,
 
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Default, Debug, Reflect, PartialEq, Component)]
+#[derive(Clone, Copy, Default, Debug, Reflect, PartialEq, Component, Serialize, Deserialize)]
 pub struct Area {
     center: Vec2,
     width: f32,
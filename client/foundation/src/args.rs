@@ -1,4 +1,9 @@
-use super::CharacterId;
+use std::path::PathBuf;
+
+use super::{
+    CharacterId, HitstunDecay, InputTiming, MatchRules, RulesPreset, StickSettings, StickSource,
+    DEFAULT_DIAGONAL_WIDTH, DEFAULT_STICK_DEAD_ZONE,
+};
 use bevy::prelude::*;
 use clap::{Parser, Subcommand};
 
@@ -11,12 +16,86 @@ pub struct WagArgs {
     pub dev: Option<Dev>,
     #[clap(long, default_value = "2")]
     pub input_delay: usize,
+    /// How forgiving input buffering and motion inputs are
+    #[clap(long, value_enum, default_value_t = InputTiming::Standard)]
+    pub input_timing: InputTiming,
+    /// Analog stick dead zone, from 0 to 1, the default for every controller
+    #[clap(long, default_value_t = DEFAULT_STICK_DEAD_ZONE)]
+    pub stick_dead_zone: f32,
+    /// How many degrees of the stick's range count as a diagonal, from 0 to 90, the default for every controller
+    #[clap(long, default_value_t = DEFAULT_DIAGONAL_WIDTH)]
+    pub diagonal_width: f32,
+    /// Which part of the controller is used for movement, can be changed per controller in controller assignment
+    #[clap(long, value_enum, default_value_t = StickSource::LeftStick)]
+    pub stick_source: StickSource,
+    /// Round count, timers and economy
+    #[clap(long, value_enum, default_value_t = RulesPreset::Standard)]
+    pub rules: RulesPreset,
+    /// RON file with match rules, fields that are left out come from the standard preset
+    #[clap(long)]
+    pub rules_file: Option<PathBuf>,
+    /// Air hits whiff once a combo has spent this many juggle points, overrides the rules
+    #[clap(long)]
+    pub juggle_points: Option<usize>,
+    /// Decays hitstun in long combos, overrides the rules
+    #[clap(long)]
+    pub hitstun_decay: bool,
+    /// RON file the shop build orders are saved in
+    #[clap(long, default_value = "build_orders.ron")]
+    pub build_orders_file: PathBuf,
+    /// Appends every match's money in and out to this file as a line of JSON
+    #[clap(long)]
+    pub economy_log: Option<PathBuf>,
 }
 impl WagArgs {
     pub fn from_cli() -> Self {
         Self::parse()
     }
 
+    pub fn stick_settings(&self) -> StickSettings {
+        StickSettings {
+            dead_zone: self.stick_dead_zone,
+            diagonal_width: self.diagonal_width,
+            source: self.stick_source,
+        }
+    }
+
+    pub fn match_rules(&self) -> MatchRules {
+        let mut rules = self.base_rules();
+
+        if let Some(points) = self.juggle_points {
+            rules.combo.juggle_points = Some(points);
+        }
+        if self.hitstun_decay && rules.combo.hitstun_decay.is_none() {
+            rules.combo.hitstun_decay = Some(HitstunDecay::default());
+        }
+
+        rules
+    }
+
+    /// A broken rules file falls back to the preset, so a typo doesn't keep the game from starting
+    fn base_rules(&self) -> MatchRules {
+        let Some(path) = &self.rules_file else {
+            return MatchRules::from(self.rules);
+        };
+
+        match std::fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|source| MatchRules::from_ron(&source).map_err(|err| err.to_string()))
+        {
+            Ok(rules) => rules,
+            Err(err) => {
+                warn!("Couldn't load rules from {}: {err}", path.display());
+                MatchRules::from(self.rules)
+            }
+        }
+    }
+
+    /// Character files are only reloaded in local dev matches, online peers would desync
+    pub fn hot_reload(&self) -> bool {
+        matches!(self.dev, Some(Dev::Local { .. }))
+    }
+
     pub fn extra_starting_money(&self) -> usize {
         if let Some(Dev::Local {
             pad1: _,
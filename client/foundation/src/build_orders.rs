@@ -0,0 +1,127 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{CharacterId, ItemId};
+
+/// Items in the order they should be bought, per character
+/// An item can be listed multiple times to buy multiple copies of stackables
+#[derive(Debug, Clone, Default, PartialEq, Resource, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BuildOrders {
+    pub orders: HashMap<CharacterId, Vec<ItemId>>,
+    /// Where edits get saved to
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl BuildOrders {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// No file is fine, it gets created on the first edit
+    pub fn load(path: &Path) -> Self {
+        let orders = match std::fs::read_to_string(path) {
+            Ok(source) => Self::from_ron(&source).unwrap_or_else(|err| {
+                warn!("Couldn't read build orders from {}: {err}", path.display());
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+
+        Self {
+            path: Some(path.to_owned()),
+            ..orders
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let source = ron::ser::to_string_pretty(self, default()).unwrap();
+        if let Err(err) = std::fs::write(path, source) {
+            warn!("Couldn't save build orders to {}: {err}", path.display());
+        }
+    }
+
+    pub fn get(&self, character: CharacterId) -> &[ItemId] {
+        self.orders
+            .get(&character)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Adds to the end, again for another copy
+    pub fn add(&mut self, character: CharacterId, item: ItemId) {
+        self.orders.entry(character).or_default().push(item);
+    }
+
+    /// Takes out the last copy
+    pub fn remove(&mut self, character: CharacterId, item: ItemId) {
+        let Some(order) = self.orders.get_mut(&character) else {
+            return;
+        };
+
+        if let Some(index) = order.iter().rposition(|id| *id == item) {
+            order.remove(index);
+        }
+    }
+
+    /// One based, for display
+    pub fn positions(&self, character: CharacterId, item: ItemId) -> Vec<usize> {
+        self.get(character)
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| **id == item)
+            .map(|(index, _)| index + 1)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn adding_and_removing() {
+        let mut orders = BuildOrders::default();
+        orders.add(CharacterId::Ronin, ItemId::ThumbTacks);
+        orders.add(CharacterId::Ronin, ItemId::Gi);
+        orders.add(CharacterId::Ronin, ItemId::ThumbTacks);
+        assert_eq!(
+            orders.get(CharacterId::Ronin),
+            &[ItemId::ThumbTacks, ItemId::Gi, ItemId::ThumbTacks]
+        );
+        assert_eq!(
+            orders.positions(CharacterId::Ronin, ItemId::ThumbTacks),
+            vec![1, 3]
+        );
+        assert!(orders.get(CharacterId::CPO).is_empty());
+
+        orders.remove(CharacterId::Ronin, ItemId::ThumbTacks);
+        assert_eq!(
+            orders.get(CharacterId::Ronin),
+            &[ItemId::ThumbTacks, ItemId::Gi]
+        );
+
+        // Nothing to remove is fine
+        orders.remove(CharacterId::CPO, ItemId::Gi);
+        assert!(orders.get(CharacterId::CPO).is_empty());
+    }
+
+    #[test]
+    fn survives_disk() {
+        let mut orders = BuildOrders::default();
+        orders.add(CharacterId::CPO, ItemId::Boots);
+
+        let source = ron::ser::to_string_pretty(&orders, default()).unwrap();
+        assert_eq!(BuildOrders::from_ron(&source).unwrap(), orders);
+    }
+}
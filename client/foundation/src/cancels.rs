@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::ActionId;
 
@@ -15,7 +16,7 @@ pub enum ActionCategory {
     Forced, // For throw recipients
 }
 
-#[derive(Debug, Reflect, PartialEq, Eq, Clone, Default, Hash)]
+#[derive(Debug, Reflect, PartialEq, Eq, Clone, Default, Hash, Serialize, Deserialize)]
 pub enum CancelType {
     #[default]
     Special,
@@ -1,10 +1,15 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, EnumIter, Component)]
+use crate::Player;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, EnumIter, Component, Serialize, Deserialize,
+)]
 pub enum CharacterId {
     #[default]
     Ronin,
@@ -52,6 +57,98 @@ pub struct Characters {
     pub p1: CharacterId,
     pub p2: CharacterId,
 }
+impl Characters {
+    pub fn get(&self, player: Player) -> CharacterId {
+        match player {
+            Player::One => self.p1,
+            Player::Two => self.p2,
+        }
+    }
+}
 
 #[derive(Debug, Resource)]
 pub struct LocalCharacter(pub CharacterId);
+
+/// Which of the character's palettes each player uses
+#[derive(Debug, Clone, Copy, Default, Resource)]
+pub struct Palettes {
+    pub p1: usize,
+    pub p2: usize,
+}
+impl Palettes {
+    pub fn get(&self, player: Player) -> usize {
+        match player {
+            Player::One => self.p1,
+            Player::Two => self.p2,
+        }
+    }
+
+    fn get_mut(&mut self, player: Player) -> &mut usize {
+        match player {
+            Player::One => &mut self.p1,
+            Player::Two => &mut self.p2,
+        }
+    }
+
+    pub fn next(&mut self, player: Player, palette_count: usize) {
+        let index = self.get_mut(player);
+        *index = (*index + 1) % palette_count;
+    }
+
+    pub fn previous(&mut self, player: Player, palette_count: usize) {
+        let index = self.get_mut(player);
+        *index = (*index + palette_count - 1) % palette_count;
+    }
+
+    pub fn reset(&mut self, player: Player) {
+        *self.get_mut(player) = 0;
+    }
+
+    /// Mirror matches would be hard to read if both had the same colors
+    pub fn avoid_mirror(self, characters: &Characters, palette_count: usize) -> Self {
+        if characters.p1 != characters.p2 || self.p1 % palette_count != self.p2 % palette_count {
+            return self;
+        }
+
+        Self {
+            p2: (self.p1 + 1) % palette_count,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn palettes_wrap() {
+        let mut palettes = Palettes::default();
+        palettes.previous(Player::One, 3);
+        assert_eq!(palettes.p1, 2);
+        palettes.next(Player::One, 3);
+        assert_eq!(palettes.p1, 0);
+        assert_eq!(palettes.p2, 0);
+    }
+
+    #[test]
+    fn mirrors_get_different_palettes() {
+        let mirror = Characters {
+            p1: CharacterId::Ronin,
+            p2: CharacterId::Ronin,
+        };
+        let resolved = Palettes { p1: 2, p2: 2 }.avoid_mirror(&mirror, 3);
+        assert_eq!((resolved.p1, resolved.p2), (2, 0));
+
+        // Different palettes or characters are left alone
+        let resolved = Palettes { p1: 1, p2: 0 }.avoid_mirror(&mirror, 3);
+        assert_eq!((resolved.p1, resolved.p2), (1, 0));
+
+        let different = Characters {
+            p1: CharacterId::Ronin,
+            p2: CharacterId::CPO,
+        };
+        let resolved = Palettes::default().avoid_mirror(&different, 3);
+        assert_eq!((resolved.p1, resolved.p2), (0, 0));
+    }
+}
@@ -50,6 +50,9 @@ pub const GI_PARRY_FLASH_COLOR: Color = Color::Srgba(ALICE_BLUE);
 pub const TRACK_SPIKES_FLASH_COLOR: Color = Color::Srgba(YELLOW);
 pub const JACKPOT_COLOR: Color = Color::srgb(0.2, 0.9, 0.1);
 pub const JACKPOT_RING_BASE_COLOR: Color = Color::WHITE;
+pub const COUNTER_HIT_COLOR: Color = Color::srgb(1.0, 0.3, 0.0);
+pub const PUNISH_COLOR: Color = Color::srgb(0.6, 0.1, 1.0);
+pub const COUNTER_RING_BASE_COLOR: Color = Color::WHITE;
 
 // Shader effects
 pub const HIT_SPARK_BASE_COLOR: Color = TRANSPARENT;
@@ -1,9 +1,12 @@
 use bevy::prelude::Component;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Component, Clone, Copy, Default)]
 pub struct Combo {
     pub hits: usize,
     pub old_health: i32,
+    /// Spent by launching hits
+    pub juggle_points: usize,
 }
 
 impl Combo {
@@ -12,11 +15,91 @@ impl Combo {
     }
 
     pub fn start_at(&mut self, defender_health: i32) {
-        self.hits = 1;
-        self.old_health = defender_health;
+        *self = Self {
+            hits: 1,
+            old_health: defender_health,
+            ..Self::default()
+        };
     }
 
     pub fn reset(&mut self) {
         *self = Self::default();
     }
+
+    pub fn out_of_juggle_points(&self, rules: &ComboRules) -> bool {
+        rules
+            .juggle_points
+            .is_some_and(|limit| self.juggle_points >= limit)
+    }
+}
+
+/// Stops infinites and touch of deaths, both halves are off with None
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComboRules {
+    /// Air hits whiff once the combo has spent this many
+    pub juggle_points: Option<usize>,
+    pub hitstun_decay: Option<HitstunDecay>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HitstunDecay {
+    /// This many hits into a combo before stun starts to decay
+    pub grace_hits: usize,
+    pub frames_per_hit: usize,
+    /// Decay won't go below this, but moves with less stun to begin with are left alone
+    pub min_stun: usize,
+}
+impl Default for HitstunDecay {
+    fn default() -> Self {
+        Self {
+            grace_hits: 5,
+            frames_per_hit: 1,
+            min_stun: 10,
+        }
+    }
+}
+impl HitstunDecay {
+    pub fn apply(&self, stun: usize, combo_hits: usize) -> usize {
+        let decay = combo_hits.saturating_sub(self.grace_hits) * self.frames_per_hit;
+        stun.saturating_sub(decay).max(self.min_stun.min(stun))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hitstun_decays() {
+        let decay = HitstunDecay::default();
+
+        assert_eq!(decay.apply(20, 1), 20);
+        assert_eq!(decay.apply(20, 5), 20);
+        assert_eq!(decay.apply(20, 8), 17);
+        assert_eq!(decay.apply(20, 100), 10);
+        assert_eq!(decay.apply(6, 100), 6);
+    }
+
+    #[test]
+    fn juggle_points_run_out() {
+        let rules = ComboRules {
+            juggle_points: Some(6),
+            ..ComboRules::default()
+        };
+        let mut combo = Combo::default();
+        combo.start_at(100);
+        assert!(!combo.out_of_juggle_points(&rules));
+
+        combo.juggle_points = 6;
+        assert!(combo.out_of_juggle_points(&rules));
+        assert!(!combo.out_of_juggle_points(&ComboRules {
+            juggle_points: None,
+            ..rules
+        }));
+
+        combo.start_at(100);
+        assert!(!combo.out_of_juggle_points(&rules));
+    }
 }
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::Player;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, EnumIter, Reflect)]
+pub enum ControlScheme {
+    #[default]
+    Classic,
+    /// Adds character specific gimmick + direction shortcuts for specials
+    Simple,
+}
+impl ControlScheme {
+    pub fn next(self) -> Self {
+        match self {
+            ControlScheme::Classic => ControlScheme::Simple,
+            ControlScheme::Simple => ControlScheme::Classic,
+        }
+    }
+}
+impl std::fmt::Display for ControlScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ControlScheme::Classic => write!(f, "classic"),
+            ControlScheme::Simple => write!(f, "simple"),
+        }
+    }
+}
+// Used for network
+impl From<ControlScheme> for u8 {
+    fn from(val: ControlScheme) -> Self {
+        ControlScheme::iter()
+            .position(|scheme| scheme == val)
+            .unwrap() as u8
+    }
+}
+impl From<u8> for ControlScheme {
+    fn from(value: u8) -> Self {
+        ControlScheme::iter()
+            .nth(value as usize)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Resource)]
+pub struct ControlSchemes {
+    pub p1: ControlScheme,
+    pub p2: ControlScheme,
+}
+impl ControlSchemes {
+    pub fn get(&self, player: Player) -> ControlScheme {
+        match player {
+            Player::One => self.p1,
+            Player::Two => self.p2,
+        }
+    }
+
+    pub fn toggle(&mut self, player: Player) {
+        match player {
+            Player::One => self.p1 = self.p1.next(),
+            Player::Two => self.p2 = self.p2.next(),
+        }
+    }
+}
@@ -1,13 +1,8 @@
 // Meter
 pub const CLASH_PARRY_METER_GAIN: i32 = 20;
 pub const GI_PARRY_METER_GAIN: i32 = 30;
+pub const SAFETY_BOOTS_METER_GAIN: i32 = 10;
 pub const METER_BAR_SEGMENT: i32 = 25;
 pub const JACKPOT_METER_GAIN: i32 = METER_BAR_SEGMENT; // For perfect
 
-// Money
-pub const STARTING_MONEY: usize = 250;
-pub const VICTORY_BONUS: usize = 200;
-pub const BASE_ROUND_MONEY: usize = 500;
-pub const ROUND_MONEY_BUILDUP: usize = 300;
-
-pub const SELL_RETURN: f32 = 0.5;
+// Money is in MatchRules
@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{ItemId, Player};
+
+/// Anything that changes how much money a player has
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Transaction {
+    StartingMoney,
+    RoundPayout,
+    MeterPayout,
+    VictoryBonus,
+    Purchase(ItemId),
+    Sale(ItemId),
+    /// Undone purchase
+    Refund(ItemId),
+    Reroll,
+    LossStreakBonus,
+    Interest,
+    PerfectRoundBonus,
+    FirstHitBonus,
+    ThrowTechBonus,
+}
+impl Transaction {
+    fn category(&self) -> &'static str {
+        match self {
+            Transaction::StartingMoney => "Starting money",
+            Transaction::RoundPayout => "Round payouts",
+            Transaction::MeterPayout => "Meter payouts",
+            Transaction::VictoryBonus => "Victory bonuses",
+            Transaction::Purchase(_) => "Purchases",
+            Transaction::Sale(_) => "Sales",
+            Transaction::Refund(_) => "Refunds",
+            Transaction::Reroll => "Rerolls",
+            Transaction::LossStreakBonus => "Loss streak bonuses",
+            Transaction::Interest => "Interest",
+            Transaction::PerfectRoundBonus => "Perfect round bonuses",
+            Transaction::FirstHitBonus => "First hit bonuses",
+            Transaction::ThrowTechBonus => "Throw tech bonuses",
+        }
+    }
+
+    /// Singular, for notifications
+    pub fn name(&self) -> &'static str {
+        match self {
+            Transaction::StartingMoney => "Starting money",
+            Transaction::RoundPayout => "Round payout",
+            Transaction::MeterPayout => "Meter payout",
+            Transaction::VictoryBonus => "Victory bonus",
+            Transaction::Purchase(_) => "Purchase",
+            Transaction::Sale(_) => "Sale",
+            Transaction::Refund(_) => "Refund",
+            Transaction::Reroll => "Reroll",
+            Transaction::LossStreakBonus => "Loss streak bonus",
+            Transaction::Interest => "Interest",
+            Transaction::PerfectRoundBonus => "Perfect round bonus",
+            Transaction::FirstHitBonus => "First hit bonus",
+            Transaction::ThrowTechBonus => "Throw tech bonus",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub player: Player,
+    /// Rounds finished when it happened
+    pub rounds_played: usize,
+    pub transaction: Transaction,
+    /// Negative for spending
+    pub amount: i64,
+}
+
+/// All money in and out during the match, for the end screen and balancing
+#[derive(Debug, Clone, Default, PartialEq, Resource, Serialize, Deserialize)]
+pub struct EconomyLedger {
+    pub entries: Vec<LedgerEntry>,
+}
+
+impl EconomyLedger {
+    pub fn record(
+        &mut self,
+        player: Player,
+        rounds_played: usize,
+        transaction: Transaction,
+        amount: i64,
+    ) {
+        self.entries.push(LedgerEntry {
+            player,
+            rounds_played,
+            transaction,
+            amount,
+        });
+    }
+
+    pub fn balance(&self, player: Player) -> i64 {
+        self.entries
+            .iter()
+            .filter(|entry| entry.player == player)
+            .map(|entry| entry.amount)
+            .sum()
+    }
+
+    /// Totals per kind of transaction, in the order they first happened
+    pub fn breakdown(&self, player: Player) -> Vec<(&'static str, i64)> {
+        let mut totals: Vec<(&'static str, i64)> = vec![];
+
+        for entry in self.entries.iter().filter(|entry| entry.player == player) {
+            let category = entry.transaction.category();
+            if let Some((_, total)) = totals.iter_mut().find(|(name, _)| *name == category) {
+                *total += entry.amount;
+            } else {
+                totals.push((category, entry.amount));
+            }
+        }
+
+        totals
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn breakdown_sums_per_category() {
+        let mut ledger = EconomyLedger::default();
+        ledger.record(Player::One, 0, Transaction::StartingMoney, 250);
+        ledger.record(Player::One, 0, Transaction::Purchase(ItemId::Boots), -100);
+        ledger.record(Player::Two, 0, Transaction::RoundPayout, 500);
+        ledger.record(Player::One, 0, Transaction::Purchase(ItemId::Gi), -50);
+        ledger.record(Player::One, 0, Transaction::Sale(ItemId::Gi), 25);
+
+        assert_eq!(
+            ledger.breakdown(Player::One),
+            vec![("Starting money", 250), ("Purchases", -150), ("Sales", 25)]
+        );
+        assert_eq!(ledger.balance(Player::One), 125);
+        assert_eq!(ledger.balance(Player::Two), 500);
+    }
+}
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Reflect)]
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Reflect, Serialize, Deserialize,
+)]
 pub enum Model {
     Ronin,
     CPO,
@@ -9,7 +12,20 @@ pub enum Model {
     TrainingStage,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Reflect)]
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Clone,
+    Copy,
+    Default,
+    Reflect,
+    Serialize,
+    Deserialize,
+)]
 pub enum RoninAnimation {
     Air,
     AirStab,
@@ -63,7 +79,20 @@ pub enum RoninAnimation {
     WalkForward,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Reflect)]
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Clone,
+    Copy,
+    Default,
+    Reflect,
+    Serialize,
+    Deserialize,
+)]
 pub enum CPOAnimation {
     BlockCrouch,
     BlockStand,
@@ -114,7 +143,7 @@ pub enum CPOAnimation {
     WalkForward,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum Animation {
     CPO(CPOAnimation),
     Ronin(RoninAnimation),
@@ -133,7 +162,9 @@ impl From<RoninAnimation> for Animation {
 }
 
 // For state to be able to return a generic animation
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Serialize, Deserialize,
+)]
 pub enum AnimationType {
     AirIdle,
     AirStun,
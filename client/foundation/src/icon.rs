@@ -1,11 +1,25 @@
 use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
 #[derive(Debug, Resource)]
 pub struct Icons(pub HashMap<Icon, Handle<Image>>);
 
-#[derive(Default, Debug, PartialEq, Eq, Hash, Clone, Copy, Asset, Reflect, EnumIter)]
+#[derive(
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Clone,
+    Copy,
+    Asset,
+    Reflect,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
 pub enum Icon {
     #[default]
     Blank,
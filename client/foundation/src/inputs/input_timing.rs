@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use clap::ValueEnum;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::Player;
+
+/// How forgiving input recognition is, picked per player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect, EnumIter, ValueEnum)]
+pub enum InputTiming {
+    Lenient,
+    #[default]
+    Standard,
+    Strict,
+}
+impl InputTiming {
+    /// How long a recognized input stays in the move buffer, unless the action overrides it
+    pub fn move_buffer(self) -> usize {
+        match self {
+            InputTiming::Lenient => 8,
+            InputTiming::Standard => 6,
+            InputTiming::Strict => 4,
+        }
+    }
+
+    /// How many frames each step of a motion input gets
+    pub fn motion_step(self, slow: bool) -> usize {
+        let fast = match self {
+            InputTiming::Lenient => 7,
+            InputTiming::Standard => 5,
+            InputTiming::Strict => 4,
+        };
+
+        if slow {
+            fast * 2
+        } else {
+            fast
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            InputTiming::Lenient => InputTiming::Standard,
+            InputTiming::Standard => InputTiming::Strict,
+            InputTiming::Strict => InputTiming::Lenient,
+        }
+    }
+}
+impl std::fmt::Display for InputTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                InputTiming::Lenient => "lenient",
+                InputTiming::Standard => "standard",
+                InputTiming::Strict => "strict",
+            }
+        )
+    }
+}
+// Used for network
+impl From<InputTiming> for u8 {
+    fn from(val: InputTiming) -> Self {
+        InputTiming::iter()
+            .position(|timing| timing == val)
+            .unwrap() as u8
+    }
+}
+impl From<u8> for InputTiming {
+    fn from(value: u8) -> Self {
+        InputTiming::iter().nth(value as usize).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Resource)]
+pub struct InputTimings {
+    pub p1: InputTiming,
+    pub p2: InputTiming,
+}
+impl InputTimings {
+    pub fn uniform(timing: InputTiming) -> Self {
+        Self {
+            p1: timing,
+            p2: timing,
+        }
+    }
+
+    pub fn get(&self, player: Player) -> InputTiming {
+        match player {
+            Player::One => self.p1,
+            Player::Two => self.p2,
+        }
+    }
+
+    pub fn cycle(&mut self, player: Player) {
+        match player {
+            Player::One => self.p1 = self.p1.next(),
+            Player::Two => self.p2 = self.p2.next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cycling_is_per_player() {
+        let mut timings = InputTimings::uniform(InputTiming::Standard);
+
+        timings.cycle(Player::One);
+        assert_eq!(timings.get(Player::One), InputTiming::Strict);
+        assert_eq!(timings.get(Player::Two), InputTiming::Standard);
+
+        // Wraps around
+        timings.cycle(Player::One);
+        assert_eq!(timings.get(Player::One), InputTiming::Lenient);
+    }
+}
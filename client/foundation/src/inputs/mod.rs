@@ -1,4 +1,5 @@
 use bevy::{math::u16, platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
 
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
@@ -11,13 +12,22 @@ pub use stick_position::StickPosition;
 mod input_state;
 pub use input_state::InputState;
 
+mod input_timing;
+pub use input_timing::{InputTiming, InputTimings};
+
+mod stick_settings;
+pub use stick_settings::{
+    StickConfig, StickSettings, StickSource, DEFAULT_DIAGONAL_WIDTH, DEFAULT_STICK_DEAD_ZONE,
+};
+
 // How many frames can you kara cancel to metered versions of moves
 pub const KARA_WINDOW: usize = 3;
-pub const STICK_DEAD_ZONE: f32 = 0.3;
 
 pub const KEYBOARD_MAGIC_CONSTANT: usize = 69;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Reflect, Default)]
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Reflect, Default, Serialize, Deserialize,
+)]
 /// Buttons of the game
 /// The name 'Button' is in prelude
 /// This is for in match inputs
@@ -75,6 +85,10 @@ pub enum MenuInput {
     Accept,
     Cancel,
     Secondary,
+    Tertiary,
+    Select,
+    LeftShoulder,
+    RightShoulder,
 }
 
 impl TryFrom<NetworkInputButton> for MenuInput {
@@ -86,6 +100,10 @@ impl TryFrom<NetworkInputButton> for MenuInput {
             NetworkInputButton::South => MenuInput::Accept,
             NetworkInputButton::West => MenuInput::Secondary,
             NetworkInputButton::East => MenuInput::Cancel,
+            NetworkInputButton::North => MenuInput::Tertiary,
+            NetworkInputButton::Select => MenuInput::Select,
+            NetworkInputButton::L1 => MenuInput::LeftShoulder,
+            NetworkInputButton::R1 => MenuInput::RightShoulder,
 
             NetworkInputButton::Up => MenuInput::Up,
             NetworkInputButton::Down => MenuInput::Down,
@@ -205,26 +223,51 @@ pub enum InputEvent {
     Release(GameButton),
 }
 
-impl From<char> for InputEvent {
-    fn from(ch: char) -> InputEvent {
-        if let Ok(number_token) = ch.to_string().parse::<i32>() {
-            InputEvent::Point(number_token.into())
-        } else {
-            match ch {
-                'f' => InputEvent::Press(GameButton::Fast),
-                'F' => InputEvent::Release(GameButton::Fast),
-                's' => InputEvent::Press(GameButton::Strong),
-                'S' => InputEvent::Release(GameButton::Strong),
-                'w' => InputEvent::Press(GameButton::Wrestling),
-                'W' => InputEvent::Release(GameButton::Wrestling),
-                'g' => InputEvent::Press(GameButton::Gimmick),
-                'G' => InputEvent::Release(GameButton::Gimmick),
-                // There is no need for negative edge on start, this whole thing is mighty sus so let's not get caught up on that shall we
-                '.' => InputEvent::Press(GameButton::Start),
-                ',' => InputEvent::Press(GameButton::Select),
-                _ => panic!("Invalid character {ch}"),
+impl InputEvent {
+    pub fn from_dsl(ch: char) -> Option<InputEvent> {
+        if let Some(number_token) = ch.to_digit(10) {
+            if number_token == 0 {
+                return None;
             }
+
+            return Some(InputEvent::Point((number_token as i32).into()));
         }
+
+        Some(match ch {
+            'f' => InputEvent::Press(GameButton::Fast),
+            'F' => InputEvent::Release(GameButton::Fast),
+            's' => InputEvent::Press(GameButton::Strong),
+            'S' => InputEvent::Release(GameButton::Strong),
+            'w' => InputEvent::Press(GameButton::Wrestling),
+            'W' => InputEvent::Release(GameButton::Wrestling),
+            'g' => InputEvent::Press(GameButton::Gimmick),
+            'G' => InputEvent::Release(GameButton::Gimmick),
+            // There is no need for negative edge on start, this whole thing is mighty sus so let's not get caught up on that shall we
+            '.' => InputEvent::Press(GameButton::Start),
+            ',' => InputEvent::Press(GameButton::Select),
+            _ => return None,
+        })
+    }
+
+    /// Inverse of from_dsl, None for events the dsl can't express
+    pub fn to_dsl(self) -> Option<char> {
+        Some(match self {
+            InputEvent::Point(stick) => char::from_digit(i32::from(stick) as u32, 10).unwrap(),
+            InputEvent::Press(GameButton::Default) | InputEvent::Release(GameButton::Default) => {
+                return None
+            }
+            InputEvent::Press(button) => button.to_dsl().chars().next().unwrap(),
+            InputEvent::Release(GameButton::Start | GameButton::Select) => return None,
+            InputEvent::Release(button) => {
+                button.to_dsl().chars().next().unwrap().to_ascii_uppercase()
+            }
+        })
+    }
+}
+
+impl From<char> for InputEvent {
+    fn from(ch: char) -> InputEvent {
+        InputEvent::from_dsl(ch).unwrap_or_else(|| panic!("Invalid character {ch}"))
     }
 }
 
@@ -347,6 +390,12 @@ impl Controllers {
             None
         }
     }
+
+    pub fn online(&self) -> bool {
+        [self.p1, self.p2]
+            .iter()
+            .any(|device| matches!(device, InputDevice::Online(_)))
+    }
 }
 
 #[derive(Debug, Resource, Clone, Copy)]
@@ -1,9 +1,12 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use std::fmt::Debug;
 use strum_macros::EnumIter;
 
-#[derive(EnumIter, Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Reflect)]
+#[derive(
+    EnumIter, Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Reflect, Serialize, Deserialize,
+)]
 pub enum StickPosition {
     NW,
     N,
@@ -0,0 +1,203 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use clap::ValueEnum;
+use strum_macros::EnumIter;
+
+use super::InputDevice;
+
+pub const DEFAULT_STICK_DEAD_ZONE: f32 = 0.3;
+// In degrees, 45 splits the circle evenly between cardinals and diagonals
+pub const DEFAULT_DIAGONAL_WIDTH: f32 = 45.0;
+
+// What cycling goes through in controller assignment
+const DEAD_ZONE_STEPS: [f32; 5] = [0.1, 0.2, 0.3, 0.4, 0.5];
+const DIAGONAL_WIDTH_STEPS: [f32; 5] = [25.0, 35.0, 45.0, 55.0, 65.0];
+
+/// Next step that is larger than the current value, wraps around
+fn next_step(value: f32, steps: &[f32]) -> f32 {
+    steps
+        .iter()
+        .copied()
+        .find(|step| *step > value + f32::EPSILON)
+        .unwrap_or(steps[0])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect, EnumIter, ValueEnum)]
+pub enum StickSource {
+    #[default]
+    LeftStick,
+    RightStick,
+    DpadOnly,
+}
+impl StickSource {
+    pub fn axes(self) -> Option<(GamepadAxis, GamepadAxis)> {
+        match self {
+            StickSource::LeftStick => Some((GamepadAxis::LeftStickX, GamepadAxis::LeftStickY)),
+            StickSource::RightStick => Some((GamepadAxis::RightStickX, GamepadAxis::RightStickY)),
+            StickSource::DpadOnly => None,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            StickSource::LeftStick => StickSource::RightStick,
+            StickSource::RightStick => StickSource::DpadOnly,
+            StickSource::DpadOnly => StickSource::LeftStick,
+        }
+    }
+}
+impl std::fmt::Display for StickSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                StickSource::LeftStick => "left stick",
+                StickSource::RightStick => "right stick",
+                StickSource::DpadOnly => "dpad only",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct StickSettings {
+    /// Radial, stick magnitudes under this read as neutral
+    pub dead_zone: f32,
+    /// Angle in degrees of each diagonal sector, cardinals get the rest
+    pub diagonal_width: f32,
+    pub source: StickSource,
+}
+impl Default for StickSettings {
+    fn default() -> Self {
+        Self {
+            dead_zone: DEFAULT_STICK_DEAD_ZONE,
+            diagonal_width: DEFAULT_DIAGONAL_WIDTH,
+            source: StickSource::default(),
+        }
+    }
+}
+impl StickSettings {
+    /// Converts an analog stick reading to one of the eight directions (or neutral)
+    pub fn digitize(&self, analog: Vec2) -> IVec2 {
+        if analog.length() < self.dead_zone || analog == Vec2::ZERO {
+            return IVec2::ZERO;
+        }
+
+        let angle = analog.y.atan2(analog.x).to_degrees().rem_euclid(360.0);
+        let half_width = self.diagonal_width.clamp(0.0, 90.0) / 2.0;
+
+        // Diagonals are centered at 45 + n*90 degrees
+        let sector = (angle / 90.0).floor();
+        let diagonal_center = sector * 90.0 + 45.0;
+        let direction_angle = if (angle - diagonal_center).abs() <= half_width {
+            diagonal_center
+        } else {
+            // Snap to the closest cardinal
+            (angle / 90.0).round() * 90.0
+        };
+
+        let radians = direction_angle.to_radians();
+        IVec2::new(radians.cos().round() as i32, radians.sin().round() as i32)
+    }
+}
+
+impl std::fmt::Display for StickSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, dead zone {:.1}, diagonals {}°",
+            self.source, self.dead_zone, self.diagonal_width
+        )
+    }
+}
+
+/// Stick settings for each input device, devices without an entry use the default
+/// The default comes from the command line
+#[derive(Debug, Clone, Default, Resource)]
+pub struct StickConfig {
+    pub default: StickSettings,
+    pub devices: HashMap<InputDevice, StickSettings>,
+}
+impl StickConfig {
+    pub fn get(&self, device: InputDevice) -> StickSettings {
+        self.devices.get(&device).copied().unwrap_or(self.default)
+    }
+
+    pub fn cycle_source(&mut self, device: InputDevice) {
+        let mut settings = self.get(device);
+        settings.source = settings.source.next();
+        self.devices.insert(device, settings);
+    }
+
+    pub fn cycle_dead_zone(&mut self, device: InputDevice) {
+        let mut settings = self.get(device);
+        settings.dead_zone = next_step(settings.dead_zone, &DEAD_ZONE_STEPS);
+        self.devices.insert(device, settings);
+    }
+
+    pub fn cycle_diagonal_width(&mut self, device: InputDevice) {
+        let mut settings = self.get(device);
+        settings.diagonal_width = next_step(settings.diagonal_width, &DIAGONAL_WIDTH_STEPS);
+        self.devices.insert(device, settings);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dead_zone_is_neutral() {
+        let settings = StickSettings::default();
+        assert_eq!(settings.digitize(Vec2::ZERO), IVec2::ZERO);
+        assert_eq!(settings.digitize(Vec2::new(0.2, 0.2)), IVec2::ZERO);
+        assert_eq!(settings.digitize(Vec2::new(0.0, -0.5)), IVec2::new(0, -1));
+    }
+
+    #[test]
+    fn settings_are_per_device() {
+        let mut config = StickConfig {
+            default: StickSettings {
+                dead_zone: 0.25,
+                ..default()
+            },
+            ..default()
+        };
+        let pad = InputDevice::Controller(Entity::from_raw(1));
+        let other = InputDevice::Controller(Entity::from_raw(2));
+
+        config.cycle_dead_zone(pad);
+        config.cycle_diagonal_width(pad);
+        assert_eq!(config.get(pad).dead_zone, 0.3);
+        assert_eq!(config.get(pad).diagonal_width, 55.0);
+        assert_eq!(config.get(other), config.default);
+
+        // Wraps around
+        config.cycle_dead_zone(pad);
+        config.cycle_dead_zone(pad);
+        config.cycle_dead_zone(pad);
+        assert_eq!(config.get(pad).dead_zone, 0.1);
+    }
+
+    #[test]
+    fn diagonal_width_changes_gate() {
+        let narrow = StickSettings {
+            diagonal_width: 10.0,
+            ..default()
+        };
+        let wide = StickSettings {
+            diagonal_width: 80.0,
+            ..default()
+        };
+
+        // About 30 degrees up from forward
+        let analog = Vec2::new(0.87, 0.5);
+        assert_eq!(narrow.digitize(analog), IVec2::new(1, 0));
+        assert_eq!(wide.digitize(analog), IVec2::new(1, 1));
+
+        for settings in [narrow, wide] {
+            assert_eq!(settings.digitize(Vec2::new(-0.7, -0.7)), IVec2::new(-1, -1));
+            assert_eq!(settings.digitize(Vec2::new(-1.0, 0.0)), IVec2::new(-1, 0));
+        }
+    }
+}
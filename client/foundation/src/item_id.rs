@@ -1,9 +1,26 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
 
-#[derive(Reflect, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+/// Names, prices and effects are in the item files under assets/items
+#[derive(
+    Reflect,
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumIter,
+)]
 pub enum ItemId {
     // Universal consumables
     PreWorkout,
+    SmokePellet,
 
     // Universal basic
     Gi,
@@ -18,6 +35,7 @@ pub enum ItemId {
     OliveOil,
 
     ThumbTacks,
+    SafetyBoots,
 
     // Universal upgrades
     GoalieGear,
@@ -41,46 +59,12 @@ pub enum ItemId {
     Fireaxe,
     SmokeBomb,
     IceCube,
-
-    // TODO: Unused
-    SafetyBoots,
 }
 
-impl ItemId {
-    pub fn display_name(&self) -> String {
-        match self {
-            Self::BladeOil => "Blade oil",
-            Self::Cigarettes => "Pack of cigs",
-            Self::ComicBook => "Comic book",
-            Self::DivingHelmet => "Diving helmet",
-            Self::FeatheredBoots => "Feathered boots",
-            Self::Gi => "Gi of the old masters",
-            Self::GoalieGear => "Goalie gear",
-            Self::HockeyPads => "Hockey pads",
-            Self::IceCube => "Ice cube",
-            Self::KunaiBelt => "Kunai belt",
-            Self::KunaiPouch => "Kunai pouch",
-            Self::MiniTasers => "Mini tasers",
-            Self::MoonBoots => "Moon boots",
-            Self::OliveOil => "Olive Oil",
-            Self::PreWorkout => "Pre-workout",
-            Self::RedPaint => "Can of red paint",
-            Self::RomaineLettuce => "Romaine lettuce",
-            Self::SafetyBoots => "Safety boots",
-            Self::SmithyCoupon => "Smithy coupon",
-            Self::SmokeBomb => "Smoke bomb",
-            Self::SpareKunai => "Spare kunai",
-            Self::ThumbTacks => "Thumbtacks",
-            Self::TrackSpikes => "Track spikes",
-            Self::Wing => "Pigeon wing",
-            Self::Boots
-            | Self::Crowbar
-            | Self::Dumbbell
-            | Self::Feather
-            | Self::Fireaxe
-            | Self::Protractor
-            | Self::Stopwatch => return format!("{self:?}"),
-        }
-        .into()
-    }
+/// Broad groups of items, so a whole group can be banned at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ItemKind {
+    Consumable,
+    Basic,
+    Upgrade,
 }
@@ -7,21 +7,30 @@ pub use area::Area;
 mod args;
 pub use args::{Dev, WagArgs};
 
+mod build_orders;
+pub use build_orders::BuildOrders;
+
 mod cancels;
 pub use cancels::{ActionCategory, CancelType};
 
 mod character_id;
-pub use character_id::{CharacterId, Characters, LocalCharacter};
+pub use character_id::{CharacterId, Characters, LocalCharacter, Palettes};
+
+mod control_scheme;
+pub use control_scheme::{ControlScheme, ControlSchemes};
 
 mod color_palette;
 pub use color_palette::*;
 
 mod combo;
-pub use combo::Combo;
+pub use combo::{Combo, ComboRules, HitstunDecay};
 
 mod economy;
 pub use economy::*;
 
+mod economy_ledger;
+pub use economy_ledger::{EconomyLedger, LedgerEntry, Transaction};
+
 mod facing;
 pub use facing::{CharacterFacing, Facing};
 
@@ -33,17 +42,24 @@ pub use icon::{Icon, Icons};
 
 mod inputs;
 pub use inputs::{
-    Controllers, GameButton, InputDevice, InputEvent, InputState, InputStream, LocalController,
-    MenuInput, NetworkInputButton, OwnedInput, StickPosition, KARA_WINDOW, KEYBOARD_MAGIC_CONSTANT,
-    STICK_DEAD_ZONE,
+    Controllers, GameButton, InputDevice, InputEvent, InputState, InputStream, InputTiming,
+    InputTimings, LocalController, MenuInput, NetworkInputButton, OwnedInput, StickConfig,
+    StickPosition, StickSettings, StickSource, DEFAULT_DIAGONAL_WIDTH, DEFAULT_STICK_DEAD_ZONE,
+    KARA_WINDOW, KEYBOARD_MAGIC_CONSTANT,
 };
 
 mod item_id;
-pub use item_id::ItemId;
+pub use item_id::{ItemId, ItemKind};
+
+mod match_rules;
+pub use match_rules::{IncomeRules, MatchRules, RoundPerformance, RulesPreset, ShopMode};
 
 mod pickups;
 pub use pickups::*;
 
+mod puppets;
+pub use puppets::*;
+
 mod player;
 pub use player::{Owner, Player, Players};
 
@@ -55,7 +71,8 @@ pub use sound::{Sound, SoundRequest, Sounds, VoiceLine, BIG_HIT_THRESHOLD, SMALL
 
 mod status;
 pub use status::{
-    Stats, StatusCondition, StatusFlag, JACKPOT_HIGH_POINT_PERCENTAGE, JACKPOT_TOTAL_DURATION,
+    Armor, EffectStats, ProjectileGuard, Stats, StatusCondition, StatusFlag,
+    ARMOR_DAMAGE_MULTIPLIER, JACKPOT_HIGH_POINT_PERCENTAGE, JACKPOT_TOTAL_DURATION,
 };
 
 mod time;
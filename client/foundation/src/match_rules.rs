@@ -0,0 +1,372 @@
+use bevy::prelude::*;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{ComboRules, ItemId, ItemKind, Player, Transaction, PRE_ROUND_DURATION};
+
+/// Starting points for the rules, a rules file can change them further
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum RulesPreset {
+    #[default]
+    Standard,
+    /// Longer sets
+    Tournament,
+    /// Short sets with more money to play around with
+    Casual,
+}
+
+/// What the shop puts on display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ShopMode {
+    /// Everything is always for sale
+    #[default]
+    Full,
+    /// This many random items every round, owned items are shown on top of these
+    Random(usize),
+}
+
+/// Extra round money on top of the flat payouts, zero turns a bonus off
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IncomeRules {
+    /// Per round lost in a row, counting the one that just ended
+    pub loss_streak_bonus: usize,
+    /// Portion of the money left unspent, paid every round
+    pub interest_rate: f32,
+    pub max_interest: usize,
+    /// Winning without taking damage
+    pub perfect_round_bonus: usize,
+    pub first_hit_bonus: usize,
+    /// Per tech
+    pub throw_tech_bonus: usize,
+}
+
+/// How a round went for one player, the income rules pay out based on this
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundPerformance {
+    /// Money before any payouts
+    pub unspent: usize,
+    pub loss_streak: usize,
+    pub perfect_round: bool,
+    pub first_hit: bool,
+    pub throw_techs: usize,
+}
+
+impl IncomeRules {
+    pub fn interest(&self, unspent: usize) -> usize {
+        ((unspent as f32 * self.interest_rate) as usize).min(self.max_interest)
+    }
+
+    /// Bonuses that actually pay something
+    pub fn bonuses(&self, performance: RoundPerformance) -> Vec<(Transaction, usize)> {
+        [
+            (
+                Transaction::LossStreakBonus,
+                self.loss_streak_bonus * performance.loss_streak,
+            ),
+            (Transaction::Interest, self.interest(performance.unspent)),
+            (
+                Transaction::PerfectRoundBonus,
+                if performance.perfect_round {
+                    self.perfect_round_bonus
+                } else {
+                    0
+                },
+            ),
+            (
+                Transaction::FirstHitBonus,
+                if performance.first_hit {
+                    self.first_hit_bonus
+                } else {
+                    0
+                },
+            ),
+            (
+                Transaction::ThrowTechBonus,
+                self.throw_tech_bonus * performance.throw_techs,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, amount)| *amount > 0)
+        .collect()
+    }
+}
+
+/// Round and economy numbers, everything that needs them reads this resource
+#[derive(Debug, Clone, PartialEq, Resource, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchRules {
+    pub rounds_to_win: usize,
+    /// Seconds
+    pub combat_duration: f32,
+    /// Seconds the other player has to finish shopping once one is done
+    pub post_shop_duration: f32,
+    pub starting_money: usize,
+    pub victory_bonus: usize,
+    pub base_round_money: usize,
+    /// Added to the round money for every round played
+    pub round_money_buildup: usize,
+    /// Portion of the price you get back when selling
+    pub sell_return: f32,
+    pub shop_mode: ShopMode,
+    /// None means no rerolls, they only do something in the random mode
+    pub reroll_cost: Option<usize>,
+    /// Items bought by one player are gone from the other player's shop
+    pub draft: bool,
+    /// Random shops are rolled from this, picked at random when left out
+    pub shop_seed: Option<u64>,
+    /// Not for sale this match
+    pub banned_items: Vec<ItemId>,
+    pub banned_kinds: Vec<ItemKind>,
+    pub income: IncomeRules,
+    /// Juggle points and hitstun decay, both off unless set
+    pub combo: ComboRules,
+}
+
+impl Default for MatchRules {
+    fn default() -> Self {
+        Self::from(RulesPreset::Standard)
+    }
+}
+
+impl From<RulesPreset> for MatchRules {
+    fn from(preset: RulesPreset) -> Self {
+        let standard = Self {
+            rounds_to_win: 3,
+            combat_duration: 100.0,
+            post_shop_duration: 10.0,
+            starting_money: 250,
+            victory_bonus: 200,
+            base_round_money: 500,
+            round_money_buildup: 300,
+            sell_return: 0.5,
+            shop_mode: ShopMode::Full,
+            reroll_cost: Some(100),
+            draft: false,
+            shop_seed: None,
+            banned_items: vec![],
+            banned_kinds: vec![],
+            income: IncomeRules::default(),
+            combo: ComboRules::default(),
+        };
+
+        match preset {
+            RulesPreset::Standard => standard,
+            RulesPreset::Tournament => Self {
+                rounds_to_win: 4,
+                post_shop_duration: 20.0,
+                ..standard.clone()
+            },
+            RulesPreset::Casual => Self {
+                rounds_to_win: 2,
+                combat_duration: 60.0,
+                starting_money: 1000,
+                sell_return: 1.0,
+                income: IncomeRules {
+                    loss_streak_bonus: 100,
+                    interest_rate: 0.1,
+                    max_interest: 100,
+                    perfect_round_bonus: 300,
+                    first_hit_bonus: 50,
+                    throw_tech_bonus: 25,
+                },
+                ..standard
+            },
+        }
+    }
+}
+
+impl MatchRules {
+    /// Fields that are left out come from the standard preset
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+
+    /// Fills in the shop seed, so it can be shared with the other player
+    pub fn seeded(self) -> Self {
+        Self {
+            shop_seed: Some(self.shop_seed.unwrap_or_else(rand::random)),
+            ..self
+        }
+    }
+
+    /// Same for both peers, changes every round and reroll
+    pub fn shop_seed(&self, player: Player, rounds_played: usize, rerolls: usize) -> u64 {
+        let player = match player {
+            Player::One => 1,
+            Player::Two => 2,
+        };
+
+        self.shop_seed
+            .unwrap_or_default()
+            .wrapping_mul(31)
+            .wrapping_add(rounds_played as u64)
+            .wrapping_mul(31)
+            .wrapping_add(rerolls as u64)
+            .wrapping_mul(31)
+            .wrapping_add(player)
+    }
+
+    // Used for network
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ron::to_string(self).unwrap().into_bytes()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_ron(std::str::from_utf8(bytes).ok()?).ok()
+    }
+
+    /// Includes the pre round countdown, as the clock starts with it
+    pub fn max_combat_duration(&self) -> f32 {
+        self.combat_duration + PRE_ROUND_DURATION
+    }
+
+    pub fn round_money(&self, rounds_played: usize) -> usize {
+        self.base_round_money + self.round_money_buildup * rounds_played
+    }
+
+    pub fn is_banned(&self, id: ItemId, kind: ItemKind) -> bool {
+        self.banned_items.contains(&id) || self.banned_kinds.contains(&kind)
+    }
+
+    pub fn sell_value(&self, price: usize) -> usize {
+        (price as f32 * self.sell_return) as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::HitstunDecay;
+
+    #[test]
+    fn partial_rules_file() {
+        let rules = MatchRules::from_ron("(rounds_to_win: 1, victory_bonus: 0)").unwrap();
+
+        assert_eq!(
+            rules,
+            MatchRules {
+                rounds_to_win: 1,
+                victory_bonus: 0,
+                ..default()
+            }
+        );
+    }
+
+    #[test]
+    fn survives_network() {
+        let rules = MatchRules::from(RulesPreset::Casual);
+        assert_eq!(MatchRules::from_bytes(&rules.to_bytes()), Some(rules));
+
+        let bans =
+            MatchRules::from_ron("(banned_items: [ComicBook], banned_kinds: [Upgrade])").unwrap();
+        assert_eq!(MatchRules::from_bytes(&bans.to_bytes()), Some(bans.clone()));
+        assert!(bans.is_banned(ItemId::ComicBook, ItemKind::Basic));
+        assert!(bans.is_banned(ItemId::Boots, ItemKind::Upgrade));
+        assert!(!bans.is_banned(ItemId::Boots, ItemKind::Basic));
+        assert_eq!(MatchRules::from_bytes(&[0xff]), None);
+
+        let seeded = MatchRules {
+            shop_mode: ShopMode::Random(8),
+            draft: true,
+            ..default()
+        }
+        .seeded();
+        assert!(seeded.shop_seed.is_some());
+        assert_eq!(MatchRules::from_bytes(&seeded.to_bytes()), Some(seeded));
+    }
+
+    #[test]
+    fn shop_seeds_differ() {
+        let rules = MatchRules {
+            shop_seed: Some(7),
+            ..default()
+        };
+
+        let base = rules.shop_seed(Player::One, 0, 0);
+        assert_eq!(base, rules.shop_seed(Player::One, 0, 0));
+        assert_ne!(base, rules.shop_seed(Player::Two, 0, 0));
+        assert_ne!(base, rules.shop_seed(Player::One, 1, 0));
+        assert_ne!(base, rules.shop_seed(Player::One, 0, 1));
+    }
+
+    #[test]
+    fn partial_income_rules() {
+        let rules =
+            MatchRules::from_ron("(income: (interest_rate: 0.1, max_interest: 50))").unwrap();
+        assert_eq!(rules.income.loss_streak_bonus, 0);
+        assert_eq!(rules.income.interest(200), 20);
+        assert_eq!(rules.income.interest(1000), 50);
+    }
+
+    #[test]
+    fn income_bonuses() {
+        let income = IncomeRules {
+            loss_streak_bonus: 100,
+            interest_rate: 0.1,
+            max_interest: 50,
+            perfect_round_bonus: 0,
+            first_hit_bonus: 40,
+            throw_tech_bonus: 25,
+        };
+
+        assert_eq!(income.bonuses(RoundPerformance::default()), vec![]);
+        assert_eq!(
+            income.bonuses(RoundPerformance {
+                unspent: 300,
+                loss_streak: 2,
+                perfect_round: true,
+                throw_techs: 1,
+                ..default()
+            }),
+            vec![
+                (Transaction::LossStreakBonus, 200),
+                (Transaction::Interest, 30),
+                // Zero bonuses are left out
+                (Transaction::ThrowTechBonus, 25),
+            ]
+        );
+        // Interest is capped
+        assert_eq!(
+            income.bonuses(RoundPerformance {
+                unspent: 1000,
+                first_hit: true,
+                ..default()
+            }),
+            vec![
+                (Transaction::Interest, 50),
+                (Transaction::FirstHitBonus, 40)
+            ]
+        );
+        assert_eq!(
+            MatchRules::default().income.bonuses(RoundPerformance {
+                unspent: 300,
+                loss_streak: 2,
+                perfect_round: true,
+                first_hit: true,
+                throw_techs: 1,
+            }),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn combo_rules_are_opt_in() {
+        assert_eq!(MatchRules::default().combo, ComboRules::default());
+        assert_eq!(MatchRules::default().combo.juggle_points, None);
+        assert_eq!(MatchRules::default().combo.hitstun_decay, None);
+
+        let rules = MatchRules::from_ron(
+            "(combo: (juggle_points: Some(6), hitstun_decay: Some((min_stun: 8))))",
+        )
+        .unwrap();
+        assert_eq!(rules.combo.juggle_points, Some(6));
+        assert_eq!(
+            rules.combo.hitstun_decay,
+            Some(HitstunDecay {
+                min_stun: 8,
+                ..default()
+            })
+        );
+    }
+}
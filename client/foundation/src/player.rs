@@ -2,6 +2,7 @@ use std::fmt::{Debug, Display};
 use strum_macros::EnumIter;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Resource)]
 pub struct Players {
@@ -30,7 +31,9 @@ impl Players {
 #[derive(Component, Deref, DerefMut, Clone, Copy)]
 pub struct Owner(pub Player);
 
-#[derive(EnumIter, Reflect, PartialEq, Eq, Clone, Copy, Debug, Hash, Component)]
+#[derive(
+    EnumIter, Reflect, PartialEq, Eq, Clone, Copy, Debug, Hash, Component, Serialize, Deserialize,
+)]
 pub enum Player {
     One,
     Two,
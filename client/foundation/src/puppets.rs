@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use crate::{Area, Model};
+
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct PuppetRequest {
+    /// Relative to the owner, mirrored with facing
+    pub spawn_point: Vec2,
+    pub hurtbox: Area,
+    /// How many hits it can take
+    pub health: usize,
+    pub model: Option<Model>,
+}
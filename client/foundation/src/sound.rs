@@ -1,5 +1,6 @@
 use bevy::{platform::collections::HashMap, prelude::*};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
@@ -8,7 +9,21 @@ pub struct Sounds {
     pub handles: HashMap<Sound, Vec<Handle<AudioSource>>>,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Reflect, EnumIter)]
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Clone,
+    Copy,
+    Default,
+    Reflect,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
 pub enum Sound {
     Whoosh,
     Clash,
@@ -172,7 +187,7 @@ impl Sound {
 pub const BIG_HIT_THRESHOLD: i32 = 30;
 pub const SMALL_HIT_THRESHOLD: i32 = 10;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum VoiceLine {
     Defeat,
     SmallHit,
@@ -196,4 +211,8 @@ impl SoundRequest {
     pub fn menu_transition() -> Self {
         Self::from(Sound::PlasticCupFlick)
     }
+
+    pub fn throw_tech() -> Self {
+        Self::from(Sound::CheekSlap)
+    }
 }
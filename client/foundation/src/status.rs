@@ -1,8 +1,10 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{ActionId, CancelType, KARA_WINDOW, WEAKEN_STATUS_COLOR};
 
-#[derive(Reflect, Debug, Clone, Copy, PartialEq, Component)]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Component, Serialize, Deserialize)]
+#[serde(default = "Stats::character_default")]
 pub struct Stats {
     // Resources
     pub max_health: i32,
@@ -39,6 +41,46 @@ pub struct Stats {
     pub retain_sharpness: bool,
 }
 
+/// Item effects leave most stats out, those are identity values instead of character defaults
+/// Use with `#[serde(deserialize_with = "EffectStats::deserialize")]`
+#[derive(Deserialize)]
+#[serde(remote = "Stats", default = "Stats::default")]
+pub struct EffectStats {
+    // Resources
+    pub max_health: i32,
+    pub starting_meter: i32,
+
+    // Damage
+    pub damage_multiplier: f32,
+    pub chip_damage: bool,
+    pub backdash_invuln: usize,
+    pub defense_meter: i32,
+
+    // Movement
+    pub walk_speed: f32,
+    pub back_walk_speed_multiplier: f32,
+    pub gravity: f32,
+    pub gravity_scaling: f32,
+    pub jump_force_multiplier: f32,
+
+    // Opener
+    pub opener_damage_multiplier: f32,
+    pub opener_meter_gain: i32,
+    pub opener_stun_frames: i32,
+
+    // Actions
+    pub action_speed_multiplier: f32,
+    pub meter_per_second: f32,
+
+    // Direct Influence
+    pub direct_influence: f32,
+
+    // Ronin
+    pub kunais: i32,
+    pub auto_sharpen: i32,
+    pub retain_sharpness: bool,
+}
+
 impl std::hash::Hash for Stats {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.max_health.hash(state);
@@ -146,6 +188,45 @@ impl Stats {
         self
     }
 
+    /// Player facing names and values
+    pub fn readable(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("Max health", self.max_health.to_string()),
+            ("Starting meter", self.starting_meter.to_string()),
+            ("Damage", percentage(self.damage_multiplier)),
+            ("Chip damage", yes_no(self.chip_damage)),
+            ("Backdash invulnerability", self.backdash_invuln.to_string()),
+            ("Meter from blocking", self.defense_meter.to_string()),
+            ("Walk speed", decimal(self.walk_speed)),
+            (
+                "Back walk speed",
+                percentage(self.back_walk_speed_multiplier),
+            ),
+            ("Gravity", decimal(self.gravity)),
+            ("Gravity scaling", decimal(self.gravity_scaling)),
+            ("Jump height", percentage(self.jump_force_multiplier)),
+            ("Opener damage", percentage(self.opener_damage_multiplier)),
+            ("Opener meter", self.opener_meter_gain.to_string()),
+            ("Opener stun", self.opener_stun_frames.to_string()),
+            ("Action speed", percentage(self.action_speed_multiplier)),
+            ("Meter per second", decimal(self.meter_per_second)),
+            ("Direct influence", decimal(self.direct_influence)),
+            ("Kunais", self.kunais.to_string()),
+            ("Auto sharpen", self.auto_sharpen.to_string()),
+            ("Keeps sharpness", yes_no(self.retain_sharpness)),
+        ]
+    }
+
+    /// "Walk speed 3 -> 3.5" for everything that is visibly different
+    pub fn changes(&self, after: &Self) -> Vec<String> {
+        self.readable()
+            .into_iter()
+            .zip(after.readable())
+            .filter(|((_, before), (_, after))| before != after)
+            .map(|((name, before), (_, after))| format!("{name} {before} -> {after}"))
+            .collect()
+    }
+
     pub fn multiply(&self, count: usize) -> Stats {
         // TODO: Make this as a whole smarter
         let mut out = Self::default();
@@ -156,7 +237,23 @@ impl Stats {
     }
 }
 
-#[derive(Reflect, Debug, Clone, PartialEq, Default, Hash, Eq)]
+fn percentage(multiplier: f32) -> String {
+    format!("{:.0}%", multiplier * 100.0)
+}
+
+fn decimal(value: f32) -> String {
+    let formatted = format!("{value:.2}");
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_owned()
+}
+
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.to_owned()
+}
+
+#[derive(Reflect, Debug, Clone, PartialEq, Default, Hash, Eq, Serialize, Deserialize)]
 pub enum StatusFlag {
     #[default]
     Default, // Not in use, here to satisfy inspectable
@@ -170,6 +267,9 @@ pub enum StatusFlag {
     Jackpot {
         target_frame: usize,
     },
+    Armor(Armor),
+    ProjectileGuard(ProjectileGuard),
+    Untechable, // Counter hit throws
 }
 
 impl StatusFlag {
@@ -181,6 +281,49 @@ impl StatusFlag {
     }
 }
 
+/// What happens to projectiles that touch someone with the guard up
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProjectileGuard {
+    /// Sends it back, it now belongs to the reflector
+    Reflect,
+    Absorb,
+}
+
+// Armored hits still hurt, just less
+pub const ARMOR_DAMAGE_MULTIPLIER: f32 = 0.5;
+
+/// Absorbs strikes without getting stunned, throws go through
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Armor {
+    /// How many more strikes it can take
+    pub hits: usize,
+    pub high: bool,
+    pub mid: bool,
+    pub low: bool,
+}
+
+impl Armor {
+    pub fn hits(hits: usize) -> Self {
+        Self {
+            hits,
+            high: true,
+            mid: true,
+            low: true,
+        }
+    }
+
+    pub fn without_lows(self) -> Self {
+        Self { low: false, ..self }
+    }
+
+    pub fn without_highs(self) -> Self {
+        Self {
+            high: false,
+            ..self
+        }
+    }
+}
+
 #[derive(Reflect, Debug, Clone, Default, PartialEq, Hash, Event)]
 pub struct StatusCondition {
     pub flag: StatusFlag,
@@ -200,3 +343,52 @@ impl StatusCondition {
 
 pub const JACKPOT_TOTAL_DURATION: f32 = 2.0;
 pub const JACKPOT_HIGH_POINT_PERCENTAGE: f32 = 0.75;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stat_changes() {
+        let before = Stats::character_default();
+        let after = before.combine(&Stats {
+            walk_speed: 0.5,
+            damage_multiplier: 1.1,
+            chip_damage: false,
+            ..default()
+        });
+
+        assert_eq!(
+            before.changes(&after),
+            vec![
+                "Damage 100% -> 110%",
+                "Chip damage yes -> no",
+                "Walk speed 3 -> 3.5",
+            ]
+        );
+        assert!(after.changes(&after).is_empty());
+    }
+
+    #[test]
+    fn left_out_stats() {
+        let character: Stats = ron::from_str("(kunais: 2)").unwrap();
+        assert_eq!(
+            character,
+            Stats {
+                kunais: 2,
+                ..Stats::character_default()
+            }
+        );
+
+        let effect =
+            EffectStats::deserialize(&mut ron::Deserializer::from_str("(kunais: 2)").unwrap())
+                .unwrap();
+        assert_eq!(
+            effect,
+            Stats {
+                kunais: 2,
+                ..default()
+            }
+        );
+    }
+}
@@ -122,6 +122,43 @@ impl RoundLog {
     pub fn rounds_played(&self) -> usize {
         self.log.len()
     }
+
+    /// Rounds lost in a row, counting back from the latest
+    pub fn loss_streak(&self, player: Player) -> usize {
+        self.log
+            .iter()
+            .rev()
+            .take_while(|round| round.winner == Some(player.other()))
+            .count()
+    }
+}
+
+/// Things that happened during the ongoing round, for the income rules
+#[derive(Debug, Resource, Default, Clone)]
+pub struct RoundStats {
+    pub first_hit: Option<Player>,
+    /// One entry per tech, for whoever teched
+    throw_techs: Vec<Player>,
+}
+impl RoundStats {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn register_hit(&mut self, player: Player) {
+        self.first_hit.get_or_insert(player);
+    }
+
+    pub fn register_throw_tech(&mut self, player: Player) {
+        self.throw_techs.push(player);
+    }
+
+    pub fn throw_techs(&self, player: Player) -> usize {
+        self.throw_techs
+            .iter()
+            .filter(|tech| **tech == player)
+            .count()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Resource)]
@@ -133,3 +170,43 @@ pub struct GameResult {
 pub struct RoundResult {
     pub winner: Option<Player>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn loss_streaks() {
+        let mut log = RoundLog::default();
+        assert_eq!(log.loss_streak(Player::One), 0);
+
+        log.add(RoundResult {
+            winner: Some(Player::One),
+        });
+        log.add(RoundResult {
+            winner: Some(Player::Two),
+        });
+        log.add(RoundResult {
+            winner: Some(Player::Two),
+        });
+        assert_eq!(log.loss_streak(Player::One), 2);
+        assert_eq!(log.loss_streak(Player::Two), 0);
+
+        // Ties break the streak
+        log.add(RoundResult { winner: None });
+        assert_eq!(log.loss_streak(Player::One), 0);
+    }
+
+    #[test]
+    fn only_the_first_hit_counts() {
+        let mut stats = RoundStats::default();
+        stats.register_hit(Player::Two);
+        stats.register_hit(Player::One);
+        stats.register_throw_tech(Player::One);
+        stats.register_throw_tech(Player::One);
+
+        assert_eq!(stats.first_hit, Some(Player::Two));
+        assert_eq!(stats.throw_techs(Player::One), 2);
+        assert_eq!(stats.throw_techs(Player::Two), 0);
+    }
+}
@@ -3,20 +3,19 @@ use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
 mod game_flow;
 pub use game_flow::{
     GameResult, GameState, InCharacterSelect, InMatch, LocalState, MatchState, OnlineState,
-    RoundLog, RoundResult,
+    RoundLog, RoundResult, RoundStats,
 };
 
 pub const FPS: f32 = 60.0;
 pub const ON_BLOCK_HITSTOP: usize = 4;
 pub const ON_HIT_HITSTOP: usize = 8;
+pub const ON_ARMOR_HITSTOP: usize = 4;
 pub const ON_THROW_HITSTOP: usize = 12;
+pub const THROW_TECH_WINDOW: usize = 7;
 
-pub const ROUNDS_TO_WIN: usize = 3;
+// Round count, combat and shop durations are in MatchRules
 pub const PRE_ROUND_DURATION: f32 = 2.0;
-pub const COMBAT_DURATION: f32 = 100.0;
-pub const MAX_COMBAT_DURATION: f32 = COMBAT_DURATION + PRE_ROUND_DURATION;
 pub const POST_ROUND_DURATION: f32 = 4.0;
-pub const POST_SHOP_DURATION: f32 = 10.0;
 
 #[derive(Reflect, Resource, Debug, Clone, Copy, Default)]
 pub struct Clock {
@@ -128,7 +127,8 @@ impl Plugin for TimePlugin {
             RollbackSchedule,
             (global_clock_update, character_clock_update).in_set(SystemStep::Clock),
         )
-        .insert_resource(RoundLog::default());
+        .insert_resource(RoundLog::default())
+        .insert_resource(RoundStats::default());
     }
 }
 
@@ -1,8 +1,9 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::Icon;
 
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub enum VisualEffect {
     #[default]
     Blank,
@@ -38,7 +39,7 @@ impl VisualEffect {
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub struct RingPulse {
     pub base_color: Color,
     pub edge_color: Color,
@@ -61,7 +62,7 @@ impl Default for RingPulse {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Smear {
     pub primary_color: Color,
     pub secondary_color: Color,
@@ -0,0 +1,12 @@
+#![no_main]
+
+use input_parsing::MotionInput;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|dsl: &str| {
+    // Malformed strings should be errors, never panics
+    if let Ok(parsed) = MotionInput::parse(dsl) {
+        // And whatever parses should survive the canonical printer
+        assert_eq!(MotionInput::parse(&parsed.to_string()), Ok(parsed));
+    }
+});
@@ -1,9 +1,10 @@
 use crate::{motion_input::MotionInput, ParrotStream};
 
-use bevy::{platform::collections::HashMap, platform::collections::HashSet, prelude::*};
+use bevy::{platform::collections::HashSet, prelude::*};
 
 use foundation::{
-    ActionId, CharacterFacing, Clock, Facing, GameButton, InputEvent, InputState, StickPosition,
+    ActionId, CharacterFacing, Clock, Facing, GameButton, InputEvent, InputState, InputTiming,
+    StickPosition,
 };
 
 #[derive(Debug, Component, Clone, Reflect)]
@@ -54,10 +55,16 @@ pub struct InputParser {
     history: Vec<InputHistory>,
     state: InputState,
     longest_move_lookback: usize,
+    timing: InputTiming,
 }
 
 impl InputParser {
-    pub(crate) fn new(new_inputs: HashMap<ActionId, String>) -> Self {
+    pub(crate) fn new(
+        new_inputs: impl IntoIterator<Item = (ActionId, String)>,
+        timing: InputTiming,
+    ) -> Self {
+        // The same action can have multiple inputs (control schemes)
+        let new_inputs: Vec<(ActionId, String)> = new_inputs.into_iter().collect();
         let motions: Vec<MotionInput> = new_inputs
             .iter()
             .map(|(_, input_str)| MotionInput::from(input_str.clone()))
@@ -72,7 +79,7 @@ impl InputParser {
                 continue;
             }
 
-            let buffer_time = motion.buffer_window_size();
+            let buffer_time = motion.buffer_window_size(timing);
             if buffer_time > longest_move_lookback {
                 longest_move_lookback = buffer_time;
             }
@@ -94,17 +101,29 @@ impl InputParser {
         Self {
             inputs,
             longest_move_lookback,
+            timing,
             ..default()
         }
     }
 
+    /// Swaps out the motions, but keeps the history so held inputs and ongoing motions carry over
+    pub fn replace_inputs(&mut self, new_inputs: impl IntoIterator<Item = (ActionId, String)>) {
+        let fresh = Self::new(new_inputs, self.timing);
+        self.inputs = fresh.inputs;
+        self.longest_move_lookback = fresh.longest_move_lookback;
+    }
+
     pub fn get_complexity(&self, action: ActionId) -> usize {
-        for (input, actions) in &self.inputs {
-            if actions.contains(&action) {
-                return input.complexity();
-            }
-        }
-        panic!("Could not find input");
+        self.inputs
+            .iter()
+            .filter(|(_, actions)| actions.contains(&action))
+            .map(|(input, _)| input.complexity())
+            .max()
+            .expect("Could not find input")
+    }
+
+    pub fn timing(&self) -> InputTiming {
+        self.timing
     }
 
     pub fn get_stick_pos(&self) -> StickPosition {
@@ -115,12 +134,20 @@ impl InputParser {
         self.state.pressed.clone()
     }
 
-    pub fn get_events(&self) -> Vec<ActionId> {
-        self.events.clone()
+    /// Buttons that went down on the given frame, ones held from before don't count
+    pub fn get_pressed_on(&self, frame: usize) -> HashSet<GameButton> {
+        self.history
+            .iter()
+            .take_while(|hist| hist.frame >= frame)
+            .filter_map(|hist| match hist.event {
+                InputEvent::Press(button) if hist.frame == frame => Some(button),
+                _ => None,
+            })
+            .collect()
     }
 
-    pub fn head_is_clear(&self) -> bool {
-        self.state.stick_position == StickPosition::Neutral && self.state.pressed.is_empty()
+    pub fn get_events(&self) -> Vec<ActionId> {
+        self.events.clone()
     }
 
     fn input_change(&mut self, events: Vec<InputEvent>, facing: Facing, frame: usize) {
@@ -144,7 +171,7 @@ impl InputParser {
             let past: Vec<InputHistory> = self
                 .history
                 .iter()
-                .take_while(|hist| hist.frame + input.buffer_window_size() >= frame)
+                .take_while(|hist| hist.frame + input.buffer_window_size(self.timing) >= frame)
                 .cloned()
                 .collect();
 
@@ -192,6 +219,7 @@ pub fn parse_input(
 #[cfg(test)]
 mod test {
     use crate::PadBundle;
+    use proptest::prelude::*;
 
     use super::*;
 
@@ -302,6 +330,204 @@ mod test {
         interface.assert_both_test_events_are_present();
     }
 
+    #[test]
+    fn timing_profile_changes_motion_window() {
+        for (timing, recognized) in [
+            (InputTiming::Lenient, true),
+            (InputTiming::Standard, false),
+            (InputTiming::Strict, false),
+        ] {
+            let mut interface = TestInterface::with_timing("236f", timing);
+
+            interface.add_stick_and_tick(StickPosition::S);
+            interface.sleep(5);
+            interface.add_stick_and_tick(StickPosition::SE);
+            interface.sleep(5);
+            interface.add_stick_and_tick(StickPosition::E);
+            interface.sleep(5);
+            interface.add_button_and_tick(GameButton::Fast);
+
+            if recognized {
+                interface.assert_test_event_is_present();
+            } else {
+                interface.assert_no_events();
+            }
+        }
+    }
+
+    #[test]
+    fn action_with_multiple_inputs() {
+        let mut interface = TestInterface::new(vec![
+            (ActionId::TestMove, "2f"),
+            (ActionId::TestMove, "{6}g"),
+        ]);
+
+        interface.add_stick_and_tick(StickPosition::E);
+        interface.add_button_and_tick(GameButton::Gimmick);
+        interface.assert_test_event_is_present();
+
+        let mut interface = TestInterface::new(vec![
+            (ActionId::TestMove, "2f"),
+            (ActionId::TestMove, "{6}g"),
+        ]);
+
+        interface.add_stick_and_tick(StickPosition::S);
+        interface.add_button_and_tick(GameButton::Fast);
+        interface.assert_test_event_is_present();
+    }
+
+    #[test]
+    fn replacing_inputs_keeps_history() {
+        let mut parser = InputParser::new([(ActionId::TestMove, "2f".to_owned())], default());
+
+        parser.input_change(vec![InputEvent::Point(StickPosition::S)], Facing::Right, 1);
+        parser.replace_inputs([(ActionId::SecondTestMove, "2s".to_owned())]);
+        parser.input_change(
+            vec![InputEvent::Press(GameButton::Strong)],
+            Facing::Right,
+            2,
+        );
+
+        assert_eq!(parser.get_events(), vec![ActionId::SecondTestMove]);
+        assert_eq!(parser.get_stick_pos(), StickPosition::S);
+    }
+
+    #[test]
+    fn only_fresh_presses() {
+        let mut parser = InputParser::default();
+
+        parser.input_change(
+            vec![InputEvent::Press(GameButton::Wrestling)],
+            Facing::Right,
+            1,
+        );
+        assert_eq!(parser.get_pressed_on(1), [GameButton::Wrestling].into());
+        assert!(parser.get_pressed_on(2).is_empty());
+        assert!(parser.get_pressed().contains(&GameButton::Wrestling));
+    }
+
+    /// Motions without state requirements, sticky steps or wildcards
+    /// Returns the dsl and the shortest event sequence that completes it
+    fn plain_motion_strategy() -> impl Strategy<Value = (String, Vec<InputEvent>)> {
+        let event = proptest::sample::select("123456789fFsSwWgG".chars().collect::<Vec<_>>());
+        (
+            proptest::collection::vec(
+                (any::<bool>(), proptest::collection::btree_set(event, 1..4)),
+                1..5,
+            ),
+            any::<bool>(),
+        )
+            .prop_map(|(steps, slow)| {
+                let mut dsl = String::new();
+                let mut sequence = vec![];
+                for (all, events) in steps {
+                    let events: String = events.into_iter().collect();
+                    if all {
+                        dsl.push_str(&format!("({events})"));
+                        sequence.extend(events.chars().map(InputEvent::from));
+                    } else {
+                        dsl.push_str(&format!("[{events}]"));
+                        sequence.push(events.chars().next().unwrap().into());
+                    }
+                }
+                if slow {
+                    dsl.push_str("|S");
+                }
+                (dsl, sequence)
+            })
+    }
+
+    fn timing_strategy() -> impl Strategy<Value = InputTiming> {
+        prop_oneof![
+            Just(InputTiming::Lenient),
+            Just(InputTiming::Standard),
+            Just(InputTiming::Strict),
+        ]
+    }
+
+    fn emissions(parser: &InputParser) -> usize {
+        parser
+            .events
+            .iter()
+            .filter(|id| **id == ActionId::TestMove)
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn completion_emits_once(
+            (dsl, sequence) in plain_motion_strategy(),
+            timing in timing_strategy(),
+            noise in proptest::collection::vec((any::<bool>(), 0..4usize), 0..10),
+        ) {
+            let mut parser = InputParser::new([(ActionId::TestMove, dsl)], timing);
+            let mut frame = 1;
+
+            for event in sequence {
+                parser.input_change(vec![event], Facing::Right, frame);
+                frame += 1;
+            }
+            prop_assert_eq!(emissions(&parser), 1);
+
+            // Unrelated inputs afterwards must not complete it again
+            for (start, gap) in noise {
+                frame += gap;
+                let button = if start { GameButton::Start } else { GameButton::Select };
+                parser.input_change(vec![InputEvent::Press(button)], Facing::Right, frame);
+                parser.input_change(vec![InputEvent::Release(button)], Facing::Right, frame + 1);
+                frame += 1;
+            }
+            prop_assert_eq!(emissions(&parser), 1);
+        }
+
+        #[test]
+        fn motions_expire_between_steps(
+            (dsl, sequence) in plain_motion_strategy(),
+            timing in timing_strategy(),
+            gap in 1..16usize,
+        ) {
+            let motion = MotionInput::from(dsl.as_str());
+            let slow = dsl.ends_with("|S");
+            let mut parser = InputParser::new([(ActionId::TestMove, dsl)], timing);
+            let mut frame = 1;
+
+            for event in sequence {
+                parser.input_change(vec![event], Facing::Right, frame);
+                frame += gap;
+            }
+
+            let in_time = motion.steps() == 1 || gap <= timing.motion_step(slow);
+            prop_assert_eq!(emissions(&parser), usize::from(in_time));
+        }
+
+        #[test]
+        fn facing_is_mirrored(stick in 1..=9i32, absolute in any::<bool>()) {
+            let stick = StickPosition::from(stick);
+            let left = InputHistory {
+                event: InputEvent::Point(stick),
+                state: InputState {
+                    stick_position: stick,
+                    ..default()
+                },
+                facing: Facing::Left,
+                ..default()
+            };
+            let right = InputHistory {
+                facing: Facing::Right,
+                ..left.clone()
+            };
+
+            let expected = if absolute { stick } else { stick.mirror() };
+            let (event, state) = left.handle_facing(absolute);
+            prop_assert_eq!(event, InputEvent::Point(expected));
+            prop_assert_eq!(state.stick_position, expected);
+
+            let (event, state) = right.handle_facing(absolute);
+            prop_assert_eq!(event, InputEvent::Point(stick));
+            prop_assert_eq!(state.stick_position, stick);
+        }
+    }
+
     struct TestInterface {
         app: App,
     }
@@ -310,6 +536,10 @@ mod test {
             TestInterface::new(vec![(ActionId::TestMove, input)])
         }
 
+        fn with_timing(input: &'static str, timing: InputTiming) -> TestInterface {
+            TestInterface::with_moves_and_timing(vec![(ActionId::TestMove, input)], timing)
+        }
+
         fn with_inputs(input: &'static str, second_input: &'static str) -> TestInterface {
             TestInterface::new(vec![
                 (ActionId::TestMove, input),
@@ -318,15 +548,20 @@ mod test {
         }
 
         fn new(moves: Vec<(ActionId, &'static str)>) -> TestInterface {
+            TestInterface::with_moves_and_timing(moves, InputTiming::Standard)
+        }
+
+        fn with_moves_and_timing(
+            moves: Vec<(ActionId, &'static str)>,
+            timing: InputTiming,
+        ) -> TestInterface {
             let mut app = App::new();
             app.add_systems(Update, parse_input);
 
             app.world_mut().spawn((
                 PadBundle::new(
-                    moves
-                        .into_iter()
-                        .map(|(id, dsl)| (id, dsl.to_string()))
-                        .collect(),
+                    moves.into_iter().map(|(id, dsl)| (id, dsl.to_string())),
+                    timing,
                 ),
                 CharacterFacing::from(Facing::Right),
             ));
@@ -351,7 +586,7 @@ mod test {
                 .app
                 .world_mut()
                 .query::<&mut ParrotStream>()
-                .iter_mut(&mut self.app.world_mut())
+                .iter_mut(self.app.world_mut())
             {
                 reader.next_read.clear();
             }
@@ -372,9 +607,9 @@ mod test {
                 .app
                 .world_mut()
                 .query::<&mut ParrotStream>()
-                .iter_mut(&mut self.app.world_mut())
+                .iter_mut(self.app.world_mut())
             {
-                reader.next_read.push(change.clone());
+                reader.next_read.push(change);
             }
         }
 
@@ -389,12 +624,12 @@ mod test {
 
         fn assert_event_is_present(&mut self, id: ActionId) {
             let events = self.get_parser_events();
-            assert!(events.contains(&id), "Event {:?} was not present", &id);
+            assert!(events.contains(&id), "Event {id:?} was not present");
         }
 
         fn assert_no_events(&mut self) {
             let events = self.get_parser_events();
-            assert!(events.is_empty(), "Expected no events, found {:?}", events,);
+            assert!(events.is_empty(), "Expected no events, found {events:?}");
         }
 
         // Running a query requires mutable access I guess?
@@ -402,7 +637,7 @@ mod test {
             self.app
                 .world_mut()
                 .query::<&InputParser>()
-                .iter(&self.app.world())
+                .iter(self.app.world())
                 .next()
                 .unwrap()
                 .events
@@ -1,5 +1,5 @@
-use bevy::{platform::collections::HashMap, prelude::*};
-use foundation::{ActionId, InMatch, RollbackSchedule, SystemStep};
+use bevy::prelude::*;
+use foundation::{ActionId, InMatch, InputTiming, RollbackSchedule, SystemStep};
 use parrot_stream::update_parrots;
 
 mod helper_types;
@@ -8,6 +8,7 @@ mod motion_input;
 mod parrot_stream;
 
 pub use input_parser::InputParser;
+pub use motion_input::{DslError, MotionInput};
 pub use parrot_stream::ParrotStream;
 
 pub struct InputParsingPlugin;
@@ -30,9 +31,9 @@ pub struct PadBundle {
     parrot: ParrotStream,
 }
 impl PadBundle {
-    pub fn new(inputs: HashMap<ActionId, String>) -> Self {
+    pub fn new(inputs: impl IntoIterator<Item = (ActionId, String)>, timing: InputTiming) -> Self {
         Self {
-            parser: InputParser::new(inputs),
+            parser: InputParser::new(inputs, timing),
             parrot: ParrotStream::default(),
         }
     }
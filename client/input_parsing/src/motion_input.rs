@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use foundation::InputEvent;
+use foundation::{InputEvent, InputTiming};
 
 use crate::{
     helper_types::{InputRequirement, RequirementMode, StateRequirement},
@@ -102,22 +102,67 @@ impl MotionInput {
         true
     }
 
-    pub fn buffer_window_size(&self) -> usize {
-        (self.steps() - 1) * if self.slow { 10 } else { 5 }
+    pub fn buffer_window_size(&self, timing: InputTiming) -> usize {
+        (self.steps() - 1) * timing.motion_step(self.slow)
     }
 }
 
-impl From<&str> for MotionInput {
-    fn from(value: &str) -> Self {
-        value.to_string().into()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DslError {
+    Empty,
+    InvalidChar(char),
+    UnclosedGroup(char),
+    EmptyGroup,
+    StickyFirst,
+    DanglingModifier,
+    AnythingWithoutState,
+    UnknownMetadata(char),
+    TooManySections,
+}
+impl std::fmt::Display for DslError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DslError::Empty => write!(f, "No requirements"),
+            DslError::InvalidChar(ch) => write!(f, "Invalid character '{ch}'"),
+            DslError::UnclosedGroup(opener) => {
+                write!(f, "Group opened with '{opener}' is never closed")
+            }
+            DslError::EmptyGroup => write!(f, "Empty group"),
+            DslError::StickyFirst => write!(f, "Sticky modifier can't be first symbol"),
+            DslError::DanglingModifier => write!(f, "Modifier without a requirement after it"),
+            DslError::AnythingWithoutState => {
+                write!(f, "Anything (*) needs a state requirement before it")
+            }
+            DslError::UnknownMetadata(ch) => write!(f, "Unknown metadata char '{ch}'"),
+            DslError::TooManySections => write!(f, "More than one metadata separator"),
+        }
     }
 }
 
-impl From<String> for MotionInput {
-    fn from(input: String) -> Self {
+fn parse_group(
+    chars: &mut impl Iterator<Item = char>,
+    opener: char,
+    closer: char,
+) -> Result<Vec<InputEvent>, DslError> {
+    let mut events = vec![];
+    loop {
+        match chars.next() {
+            None => return Err(DslError::UnclosedGroup(opener)),
+            Some(ch) if ch == closer => break,
+            Some(ch) => events.push(InputEvent::from_dsl(ch).ok_or(DslError::InvalidChar(ch))?),
+        }
+    }
+    Ok(events)
+}
+
+impl MotionInput {
+    pub fn parse(input: &str) -> Result<Self, DslError> {
         let mut split = input.split('|');
         let sequence = split.next().unwrap();
         let metadata = split.next().unwrap_or("");
+        if split.next().is_some() {
+            return Err(DslError::TooManySections);
+        }
 
         let mut incomplete = InputRequirement::default();
         let mut complete = vec![];
@@ -128,20 +173,16 @@ impl From<String> for MotionInput {
             match ch {
                 // Modifiers
                 '+' => {
-                    debug_assert!(
-                        !complete.is_empty(),
-                        "Sticky modifier can't be first symbol"
-                    );
+                    if complete.is_empty() {
+                        return Err(DslError::StickyFirst);
+                    }
 
                     incomplete.sticky = true;
                 }
                 '{' => {
-                    incomplete.state_requirement = chars
-                        .by_ref()
-                        .take_while(|nxt| *nxt != '}')
-                        .fold(StateRequirement::default(), |mut acc, nxt| {
-                            let ev: InputEvent = nxt.into();
-
+                    incomplete.state_requirement = parse_group(&mut chars, '{', '}')?
+                        .into_iter()
+                        .fold(StateRequirement::default(), |mut acc, ev| {
                             match ev {
                                 InputEvent::Point(stick_position) => acc.stick.push(stick_position),
                                 InputEvent::Press(game_button) => {
@@ -156,43 +197,50 @@ impl From<String> for MotionInput {
                         });
                 }
                 // Steps
-                '[' => {
-                    incomplete.mode = RequirementMode::Any(
-                        chars
-                            .by_ref()
-                            .take_while(|nxt| *nxt != ']')
-                            .map(|nxt| nxt.into())
-                            .collect(),
-                    );
-                    complete.push(incomplete);
-                    incomplete = InputRequirement::default();
-                }
-                '(' => {
-                    incomplete.mode = RequirementMode::All(
-                        chars
-                            .by_ref()
-                            .take_while(|nxt| *nxt != ')')
-                            .map(|nxt| nxt.into())
-                            .collect(),
-                    );
+                '[' | '(' => {
+                    let events = if ch == '[' {
+                        parse_group(&mut chars, '[', ']')?
+                    } else {
+                        parse_group(&mut chars, '(', ')')?
+                    };
+
+                    if events.is_empty() {
+                        return Err(DslError::EmptyGroup);
+                    }
+
+                    incomplete.mode = if ch == '[' {
+                        RequirementMode::Any(events)
+                    } else {
+                        RequirementMode::All(events)
+                    };
                     complete.push(incomplete);
                     incomplete = InputRequirement::default();
                 }
                 '*' => {
+                    if incomplete.state_requirement.is_empty() {
+                        return Err(DslError::AnythingWithoutState);
+                    }
+
                     incomplete.mode = RequirementMode::Anything;
-                    debug_assert!(!incomplete.state_requirement.is_empty());
                     complete.push(incomplete);
                     incomplete = InputRequirement::default();
                 }
                 _ => {
-                    incomplete.mode = RequirementMode::Any(vec![ch.into()]);
+                    let event = InputEvent::from_dsl(ch).ok_or(DslError::InvalidChar(ch))?;
+                    incomplete.mode = RequirementMode::Any(vec![event]);
                     complete.push(incomplete);
                     incomplete = InputRequirement::default();
                 }
             }
         }
 
-        debug_assert!(!complete.is_empty(), "No requirements");
+        if incomplete != InputRequirement::default() {
+            return Err(DslError::DanglingModifier);
+        }
+
+        if complete.is_empty() {
+            return Err(DslError::Empty);
+        }
 
         let mut out = Self {
             requirements: complete.into_iter().rev().collect(),
@@ -207,11 +255,89 @@ impl From<String> for MotionInput {
                 'S' => {
                     out.slow = true;
                 }
-                unknown => panic!("Unknown char ̈́'{unknown}'"),
+                unknown => return Err(DslError::UnknownMetadata(unknown)),
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+impl From<&str> for MotionInput {
+    fn from(value: &str) -> Self {
+        MotionInput::parse(value).unwrap_or_else(|err| panic!("Invalid input '{value}': {err}"))
+    }
+}
+
+impl From<String> for MotionInput {
+    fn from(input: String) -> Self {
+        input.as_str().into()
+    }
+}
+
+fn write_events(f: &mut std::fmt::Formatter<'_>, events: &[InputEvent]) -> std::fmt::Result {
+    for event in events {
+        write!(f, "{}", event.to_dsl().ok_or(std::fmt::Error)?)?;
+    }
+    Ok(())
+}
+
+/// Prints the canonical form of the input, parsing it gives back an equal MotionInput
+impl std::fmt::Display for MotionInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Requirements are stored latest first
+        for requirement in self.requirements.iter().rev() {
+            if requirement.sticky {
+                write!(f, "+")?;
+            }
+
+            let state = &requirement.state_requirement;
+            if !state.is_empty() {
+                write!(f, "{{")?;
+                for stick in &state.stick {
+                    write_events(f, &[InputEvent::Point(*stick)])?;
+                }
+                for (button, pressed) in &state.buttons {
+                    write_events(
+                        f,
+                        &[if *pressed {
+                            InputEvent::Press(*button)
+                        } else {
+                            InputEvent::Release(*button)
+                        }],
+                    )?;
+                }
+                write!(f, "}}")?;
+            }
+
+            match &requirement.mode {
+                RequirementMode::Any(events) if events.len() == 1 => write_events(f, events)?,
+                RequirementMode::Any(events) => {
+                    write!(f, "[")?;
+                    write_events(f, events)?;
+                    write!(f, "]")?;
+                }
+                RequirementMode::All(events) => {
+                    write!(f, "(")?;
+                    write_events(f, events)?;
+                    write!(f, ")")?;
+                }
+                RequirementMode::Anything => write!(f, "*")?,
+                RequirementMode::None => return Err(std::fmt::Error),
             }
         }
 
-        out
+        if self.absolute || self.slow {
+            write!(f, "|")?;
+            if self.absolute {
+                write!(f, "A")?;
+            }
+            if self.slow {
+                write!(f, "S")?;
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -219,7 +345,10 @@ impl From<String> for MotionInput {
 mod test {
     use super::*;
     use bevy::platform::collections::HashSet;
-    use foundation::{Facing::*, GameButton::*, InputEvent::*, InputState, StickPosition::*};
+    use foundation::{
+        Facing::*, GameButton::*, InputEvent::*, InputState, StickPosition, StickPosition::*,
+    };
+    use proptest::prelude::*;
 
     #[test]
     fn hadouken() {
@@ -457,4 +586,173 @@ mod test {
 
         assert!(the_move.contained_in(&hist));
     }
+
+    #[test]
+    fn invalid_inputs_are_errors() {
+        for (input, error) in [
+            ("", DslError::Empty),
+            ("x", DslError::InvalidChar('x')),
+            ("0", DslError::InvalidChar('0')),
+            ("[26", DslError::UnclosedGroup('[')),
+            ("{2", DslError::UnclosedGroup('{')),
+            ("()", DslError::EmptyGroup),
+            ("+2", DslError::StickyFirst),
+            ("2{2}", DslError::DanglingModifier),
+            ("*f", DslError::AnythingWithoutState),
+            ("f|X", DslError::UnknownMetadata('X')),
+            ("f|A|S", DslError::TooManySections),
+        ] {
+            assert_eq!(MotionInput::parse(input), Err(error), "{input}");
+        }
+    }
+
+    #[test]
+    fn canonical_form() {
+        for (input, canonical) in [
+            ("236f", "236f"),
+            ("[6]", "6"),
+            ("2+[36]f|SA", "2+[36]f|AS"),
+            ("{2}*8f", "{2}*8f"),
+            ("{sG2}f", "{2sG}f"),
+        ] {
+            assert_eq!(MotionInput::from(input).to_string(), canonical);
+        }
+    }
+
+    const DSL_EVENT: &str = "[1-9fFsSwWgG.,]";
+
+    fn dsl_events(count: &str) -> impl Strategy<Value = String> {
+        proptest::string::string_regex(&format!("{DSL_EVENT}{count}")).unwrap()
+    }
+
+    fn requirement_strategy() -> impl Strategy<Value = String> {
+        (
+            any::<bool>(),
+            proptest::option::of(dsl_events("{1,3}")),
+            prop_oneof![
+                dsl_events(""),
+                dsl_events("{1,4}").prop_map(|events| format!("[{events}]")),
+                dsl_events("{1,4}").prop_map(|events| format!("({events})")),
+                Just("*".to_owned()),
+            ],
+        )
+            .prop_map(|(sticky, state, step)| {
+                // Anything always needs a state to check
+                let state = match (state, step.as_str()) {
+                    (None, "*") => Some("5".to_owned()),
+                    (state, _) => state,
+                };
+
+                format!(
+                    "{}{}{}",
+                    if sticky { "+" } else { "" },
+                    state
+                        .map(|state| format!("{{{state}}}"))
+                        .unwrap_or_default(),
+                    step
+                )
+            })
+    }
+
+    fn dsl_strategy() -> impl Strategy<Value = String> {
+        (
+            proptest::collection::vec(requirement_strategy(), 1..6),
+            prop_oneof![
+                Just(""),
+                Just("|"),
+                Just("|A"),
+                Just("|S"),
+                Just("|AS"),
+                Just("|SA")
+            ],
+        )
+            .prop_map(|(requirements, metadata)| {
+                let mut dsl = requirements.concat();
+                // Sticky can't be first
+                if dsl.starts_with('+') {
+                    dsl.remove(0);
+                }
+                dsl + metadata
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn dsl_round_trips(dsl in dsl_strategy()) {
+            let parsed = MotionInput::parse(&dsl).unwrap();
+            let printed = parsed.to_string();
+
+            prop_assert_eq!(MotionInput::parse(&printed), Ok(parsed));
+            prop_assert_eq!(MotionInput::from(printed.as_str()).to_string(), printed);
+        }
+
+        #[test]
+        fn parser_never_panics(dsl in "\\PC{0,32}") {
+            let _ = MotionInput::parse(&dsl);
+        }
+
+        #[test]
+        fn parsed_inputs_always_print(dsl in "[0-9fFsSwWgG.,+{}()\\[\\]*|AS]{0,24}") {
+            if let Ok(parsed) = MotionInput::parse(&dsl) {
+                prop_assert_eq!(MotionInput::parse(&parsed.to_string()), Ok(parsed));
+            }
+        }
+
+        #[test]
+        fn mirrored_histories_match_alike(
+            dsl in dsl_strategy(),
+            history in proptest::collection::vec(
+                (dsl_events(""), 1..=9i32),
+                0..12,
+            ),
+        ) {
+            let motion = MotionInput::from(dsl.as_str());
+            let (right, left): (Vec<_>, Vec<_>) = history
+                .into_iter()
+                .map(|(event, stick)| {
+                    let event = InputEvent::from(event.chars().next().unwrap());
+                    let stick = StickPosition::from(stick);
+                    let mirrored_event = match event {
+                        Point(sp) => Point(sp.mirror()),
+                        other => other,
+                    };
+
+                    (
+                        InputHistory {
+                            event,
+                            state: InputState {
+                                stick_position: stick,
+                                ..default()
+                            },
+                            facing: Right,
+                            ..default()
+                        },
+                        InputHistory {
+                            event: mirrored_event,
+                            state: InputState {
+                                stick_position: stick.mirror(),
+                                ..default()
+                            },
+                            facing: Left,
+                            ..default()
+                        },
+                    )
+                })
+                .unzip();
+
+            if motion.absolute {
+                // Absolute inputs read the stick as is
+                let flipped: Vec<_> = right
+                    .iter()
+                    .map(|hist| InputHistory {
+                        facing: Left,
+                        ..hist.clone()
+                    })
+                    .collect();
+                prop_assert_eq!(motion.contained_in(&right), motion.contained_in(&flipped));
+            } else {
+                prop_assert_eq!(motion.contained_in(&right), motion.contained_in(&left));
+            }
+        }
+    }
 }
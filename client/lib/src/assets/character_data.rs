@@ -0,0 +1,304 @@
+use std::hash::{Hash, Hasher};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    ecs::system::SystemParam,
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use bevy_ggrs::checksum_hasher;
+use strum::IntoEnumIterator;
+
+use characters::{Character, CharacterDefinition, ItemCatalog};
+use foundation::{CharacterId, Characters, ControlSchemes, MatchRules, Player};
+use input_parsing::InputParser;
+
+use super::AssetsLoading;
+
+#[derive(Debug, Resource, Deref)]
+pub struct CharacterDefinitions(pub HashMap<CharacterId, Handle<CharacterDefinition>>);
+
+/// None is for the universal items
+#[derive(Debug, Resource, Deref)]
+pub struct ItemCatalogs(pub HashMap<Option<CharacterId>, Handle<ItemCatalog>>);
+
+#[derive(Debug)]
+pub enum DataFileError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl std::fmt::Display for DataFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataFileError::Io(err) => write!(f, "Could not read data file: {err}"),
+            DataFileError::Ron(err) => write!(f, "Invalid data file: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for DataFileError {}
+
+impl From<std::io::Error> for DataFileError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<ron::error::SpannedError> for DataFileError {
+    fn from(value: ron::error::SpannedError) -> Self {
+        Self::Ron(value)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CharacterDefinitionLoader;
+
+impl AssetLoader for CharacterDefinitionLoader {
+    type Asset = CharacterDefinition;
+    type Settings = ();
+    type Error = DataFileError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["character.ron"]
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ItemCatalogLoader;
+
+impl AssetLoader for ItemCatalogLoader {
+    type Asset = ItemCatalog;
+    type Settings = ();
+    type Error = DataFileError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["items.ron"]
+    }
+}
+
+pub fn character_definitions(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut loading_assets: ResMut<AssetsLoading>,
+) {
+    let handles: HashMap<CharacterId, Handle<CharacterDefinition>> = CharacterId::iter()
+        .map(|id| (id, asset_server.load(CharacterDefinition::path(id))))
+        .collect();
+
+    loading_assets
+        .0
+        .extend(handles.values().cloned().map(|h| h.untyped()));
+
+    commands.insert_resource(CharacterDefinitions(handles));
+}
+
+pub fn item_catalogs(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut loading_assets: ResMut<AssetsLoading>,
+) {
+    let handles: HashMap<Option<CharacterId>, Handle<ItemCatalog>> = [None]
+        .into_iter()
+        .chain(CharacterId::iter().map(Some))
+        .map(|id| (id, asset_server.load(ItemCatalog::path(id))))
+        .collect();
+
+    loading_assets
+        .0
+        .extend(handles.values().cloned().map(|h| h.untyped()));
+
+    commands.insert_resource(ItemCatalogs(handles));
+}
+
+/// The data files can be edited without recompiling, the embedded ones are a fallback
+#[derive(SystemParam)]
+pub struct CharacterData<'w> {
+    definition_handles: Res<'w, CharacterDefinitions>,
+    definitions: Res<'w, Assets<CharacterDefinition>>,
+    catalog_handles: Res<'w, ItemCatalogs>,
+    catalogs: Res<'w, Assets<ItemCatalog>>,
+    rules: Res<'w, MatchRules>,
+}
+
+impl CharacterData<'_> {
+    pub fn build(&self, id: CharacterId) -> Character {
+        let mut character = self.definition(id).build(&self.items(id));
+
+        character.ban_items(&self.rules);
+        character
+    }
+
+    pub fn items(&self, id: CharacterId) -> ItemCatalog {
+        self.catalog(None).combined(&self.catalog(Some(id)))
+    }
+
+    /// Online peers compare these, as different data files would desync the match
+    pub fn data_hash(&self) -> u64 {
+        let mut hasher = checksum_hasher();
+
+        for id in CharacterId::iter() {
+            ron::to_string(&self.definition(id))
+                .unwrap()
+                .hash(&mut hasher);
+        }
+        for id in [None].into_iter().chain(CharacterId::iter().map(Some)) {
+            ron::to_string(&self.catalog(id)).unwrap().hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    fn definition(&self, id: CharacterId) -> CharacterDefinition {
+        self.definition_handles
+            .get(&id)
+            .and_then(|handle| self.definitions.get(handle))
+            .cloned()
+            .unwrap_or_else(|| CharacterDefinition::embedded(id))
+    }
+
+    fn catalog(&self, id: Option<CharacterId>) -> ItemCatalog {
+        self.catalog_handles
+            .get(&id)
+            .and_then(|handle| self.catalogs.get(handle))
+            .cloned()
+            .unwrap_or_else(|| ItemCatalog::embedded(id))
+    }
+}
+
+/// Rebuilds the characters of an ongoing match when their definition or item files change
+/// Player state, gauges and positions are left alone so the match keeps going
+/// A running move that no longer exists is ended on the next frame
+pub fn hot_reload_characters(
+    mut definition_events: EventReader<AssetEvent<CharacterDefinition>>,
+    mut catalog_events: EventReader<AssetEvent<ItemCatalog>>,
+    data: CharacterData,
+    maybe_characters: Option<Res<Characters>>,
+    maybe_schemes: Option<Res<ControlSchemes>>,
+    mut players: Query<(&Player, &mut Character, &mut InputParser)>,
+) {
+    let Some(characters) = maybe_characters else {
+        definition_events.clear();
+        catalog_events.clear();
+        return;
+    };
+    let schemes = maybe_schemes.map(|schemes| *schemes).unwrap_or_default();
+    let mut pending = HashSet::new();
+
+    for event in definition_events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+
+        pending.extend(
+            data.definition_handles
+                .iter()
+                .filter(|(_, handle)| handle.id() == *id)
+                .map(|(character_id, _)| *character_id),
+        );
+    }
+
+    for event in catalog_events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+
+        for (catalog_id, _) in data
+            .catalog_handles
+            .iter()
+            .filter(|(_, handle)| handle.id() == *id)
+        {
+            match catalog_id {
+                // Universal items change everyone
+                None => pending.extend(CharacterId::iter()),
+                Some(character_id) => {
+                    pending.insert(*character_id);
+                }
+            }
+        }
+    }
+
+    for character_id in pending.drain() {
+        for (player, mut character, mut parser) in &mut players {
+            let player_character = match player {
+                Player::One => characters.p1,
+                Player::Two => characters.p2,
+            };
+
+            if player_character != character_id {
+                continue;
+            }
+
+            *character = data.build(character_id);
+            parser.replace_inputs(character.get_inputs(schemes.get(*player)));
+            info!("Reloaded {character_id} for player {player}");
+
+            for error in character.validate() {
+                warn!("{character_id}: {error}");
+            }
+            for duplicate in data.items(character_id).duplicates() {
+                warn!("{character_id}: {duplicate:?} is listed more than once");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use foundation::ControlScheme;
+    use input_parsing::MotionInput;
+
+    use crate::assets::animations::animation_paths;
+
+    use super::*;
+
+    #[test]
+    fn all_inputs_parse() {
+        for id in CharacterId::iter() {
+            let character = Character::from(id);
+            for scheme in ControlScheme::iter() {
+                for (action, input) in character.get_inputs(scheme) {
+                    if let Err(err) = MotionInput::parse(&input) {
+                        panic!("{id} {action:?} has an invalid input '{input}': {err}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn all_animations_have_clips() {
+        let paths = animation_paths();
+
+        for id in CharacterId::iter() {
+            for animation in Character::from(id).animations() {
+                assert!(
+                    paths.contains_key(&animation),
+                    "{id} uses {animation:?}, which has no clip"
+                );
+            }
+        }
+    }
+}
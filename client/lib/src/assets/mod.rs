@@ -3,6 +3,7 @@ use bevy::prelude::*;
 mod animations;
 mod announcer;
 mod asset_updater;
+mod character_data;
 mod loaders;
 mod materials;
 mod models;
@@ -12,12 +13,14 @@ mod vfx;
 pub use animations::{AnimationHelper, AnimationHelperSetup, Animations};
 pub use announcer::Announcer;
 pub use asset_updater::{play_voiceline, start_animation};
+pub use character_data::{CharacterData, CharacterDefinitions};
 pub use materials::{ExtendedFlashMaterial, FlashMaterial};
 pub use models::{shake_character, CharacterShake, Models, PlayerModelHook};
 pub use music::Music;
 pub use vfx::start_relative_vfx;
 
-use foundation::{MatchState, RollbackSchedule, SystemStep};
+use characters::{CharacterDefinition, ItemCatalog};
+use foundation::{GameState, MatchState, RollbackSchedule, SystemStep, WagArgs};
 
 #[derive(Debug, Resource)]
 pub struct Fonts {
@@ -33,6 +36,10 @@ impl Plugin for AssetsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AssetsLoading>()
             .init_resource::<Announcer>()
+            .init_asset::<CharacterDefinition>()
+            .init_asset_loader::<character_data::CharacterDefinitionLoader>()
+            .init_asset::<ItemCatalog>()
+            .init_asset_loader::<character_data::ItemCatalogLoader>()
             .add_plugins((
                 MaterialPlugin::<materials::HitSparkMaterial>::default(),
                 MaterialPlugin::<materials::BlankMaterial>::default(),
@@ -64,9 +71,20 @@ impl Plugin for AssetsPlugin {
                     loaders::models,
                     loaders::animations,
                     loaders::sounds,
+                    character_data::character_definitions,
+                    character_data::item_catalogs,
                 ),
             )
             .add_systems(PostStartup, music::setup_music)
+            // Outside the rollback schedule, so the swap happens between frames
+            .add_systems(
+                Update,
+                character_data::hot_reload_characters.run_if(
+                    |args: Res<WagArgs>, game_state: Res<State<GameState>>| {
+                        args.hot_reload() && !game_state.get().is_online()
+                    },
+                ),
+            )
             .add_systems(
                 RollbackSchedule,
                 (animations::setup_helpers, models::prep_player_gltf)
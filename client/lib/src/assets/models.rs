@@ -31,7 +31,7 @@ pub(super) fn model_paths() -> HashMap<Model, &'static str> {
 }
 
 #[derive(Component, Debug)]
-pub struct PlayerModelHook(pub HashMap<&'static str, Color>);
+pub struct PlayerModelHook(pub HashMap<String, Color>);
 
 #[allow(clippy::too_many_arguments)]
 pub fn prep_player_gltf(
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use characters::{ProjectileBehaviour, ToHit};
 
 const FRAMES_BETWEEN_HITS: usize = 10;
 
@@ -7,15 +8,34 @@ pub struct HitTracker {
     pub hits: usize,
     pub last_hit_frame: Option<usize>,
     pub hit_intangible: bool,
+    pub hit_juggle_limit: bool,
+    /// Traps can't hit before this
+    pub armed_frame: usize,
 }
 impl HitTracker {
     pub fn new(hits: usize) -> Self {
         Self { hits, ..default() }
     }
+    /// Traps arm after a delay, everything else can hit right away
+    pub fn for_spawn(to_hit: &ToHit, frame: usize) -> Self {
+        let tracker = Self::new(to_hit.hits);
+        match to_hit.behaviour {
+            ProjectileBehaviour::Trap(arming_delay) => tracker.armed_at(frame + arming_delay),
+            _ => tracker,
+        }
+    }
+    pub fn armed_at(self, armed_frame: usize) -> Self {
+        Self {
+            armed_frame,
+            ..self
+        }
+    }
     pub fn active(&self, current_frame: usize) -> bool {
-        self.last_hit_frame
-            .map(|frame| frame + FRAMES_BETWEEN_HITS <= current_frame)
-            .unwrap_or(true)
+        current_frame >= self.armed_frame
+            && self
+                .last_hit_frame
+                .map(|frame| frame + FRAMES_BETWEEN_HITS <= current_frame)
+                .unwrap_or(true)
     }
     pub fn register_hit(&mut self, current_frame: usize) {
         self.hits -= 1;
@@ -28,6 +48,28 @@ impl Default for HitTracker {
             hits: 1,
             last_hit_frame: None,
             hit_intangible: false,
+            hit_juggle_limit: false,
+            armed_frame: 0,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traps_arm_after_delay() {
+        let trap = HitTracker::for_spawn(
+            &ToHit {
+                behaviour: ProjectileBehaviour::Trap(30),
+                ..default()
+            },
+            100,
+        );
+        assert!(!trap.active(129));
+        assert!(trap.active(130));
+
+        assert!(HitTracker::for_spawn(&ToHit::default(), 100).active(100));
+    }
+}
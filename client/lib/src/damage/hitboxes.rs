@@ -3,6 +3,7 @@ use bevy::prelude::*;
 use bevy_ggrs::AddRollbackCommandExtension;
 use characters::{Attack, Hitbox, Lifetime};
 use foundation::{Area, CharacterFacing, Clock, MatchState, Owner, Player};
+use player_state::PlayerState;
 
 use crate::{
     assets::Models,
@@ -11,7 +12,7 @@ use crate::{
     movement::{Follow, ObjectVelocity},
 };
 
-use super::HitTracker;
+use super::{projectiles::Projectile, HitTracker};
 
 #[derive(Component, Clone, Copy)]
 pub struct LifetimeFlags {
@@ -61,7 +62,7 @@ impl HitboxSpawner {
         let mut builder = commands.spawn((
             transform,
             GlobalTransform::from_translation(absolute_position),
-            HitTracker::new(attack.to_hit.hits),
+            HitTracker::for_spawn(&attack.to_hit, frame),
             Owner(player),
             hitbox,
             attack.clone(),
@@ -82,7 +83,14 @@ impl HitboxSpawner {
         }
 
         if attack.to_hit.projectile {
-            builder.insert(ProjectileMarker);
+            builder.insert((
+                ProjectileMarker,
+                Projectile {
+                    behaviour: attack.to_hit.behaviour,
+                    durability: attack.to_hit.durability,
+                    spawn_frame: frame,
+                },
+            ));
         } else {
             builder.insert(Follow {
                 target: parent,
@@ -117,11 +125,16 @@ pub fn spawn_hitbox(
         Entity,
         &CharacterFacing,
         &Player,
+        &mut PlayerState,
     )>,
 ) {
-    let (mut spawner, tf, parent, facing, player) = query.get_mut(trigger.target()).unwrap();
+    let (mut spawner, tf, parent, facing, player, mut state) =
+        query.get_mut(trigger.target()).unwrap();
     let SpawnHitbox(attack) = trigger.event();
 
+    // Used to tell startup from recovery for counter hits
+    state.register_attack();
+
     spawner.spawn_attack(
         &mut commands,
         &models,
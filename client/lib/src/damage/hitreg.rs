@@ -1,34 +1,38 @@
 use bevy::{ecs::query::QueryData, prelude::*};
 
 use characters::{
-    ActionEvent, Attack, AttackHeight, BlockType, Character, GaugeType, Gauges, HitEffect, HitInfo,
-    Hitbox, Hurtboxes, Inventory,
+    ActionEvent, Attack, AttackHeight, BlockType, Character, CounterHit, GaugeType, Gauges,
+    HitEffect, HitInfo, Hitbox, Hurtboxes, Inventory, ItemTrigger,
 };
 use foundation::{
-    Area, CharacterClock, CharacterFacing, Clock, Combo, Owner, Player, Players, Sound,
-    SoundRequest, Stats, StatusFlag, StickPosition, VfxRequest, VisualEffect,
-    CLASH_PARRY_METER_GAIN, GI_PARRY_METER_GAIN,
+    Area, Armor, CharacterClock, CharacterFacing, Clock, Combo, HitstunDecay, MatchRules, Owner,
+    Player, Players, RingPulse, RoundStats, Sound, SoundRequest, Stats, StatusFlag, StickPosition,
+    VfxRequest, VisualEffect, ARMOR_DAMAGE_MULTIPLIER, CLASH_PARRY_METER_GAIN, GI_PARRY_METER_GAIN,
+    ON_ARMOR_HITSTOP, THROW_TECH_RING_BASE_COLOR, THROW_TECH_RING_EDGE_COLOR,
 };
 use input_parsing::InputParser;
 use player_state::PlayerState;
 
 use crate::{
     event_spreading::{
-        LaunchImpulse, SnapToOpponent, SpawnVfx, UpdateBlockstun, UpdateHitstun, ZoomCamera,
+        EndAction, LaunchImpulse, SnapToOpponent, SpawnVfx, ThrowTech, UpdateBlockstun,
+        UpdateHitstun, ZoomCamera,
     },
     movement::{PlayerVelocity, Pushbox},
     ui::Notifications,
 };
 
-use super::{hitboxes::ProjectileMarker, HitTracker, HitboxSpawner};
+use super::{hitboxes::ProjectileMarker, projectiles::Projectile, HitTracker, HitboxSpawner};
+
+const THROW_TECH_PUSHBACK: f32 = 8.0;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub(super) enum ConnectionType {
     Strike,
     Block,
     Parry,
+    Armor,
     Throw,
-    Tech,
     Stunlock,
 }
 
@@ -39,6 +43,7 @@ pub(super) struct AttackConnection {
     overlap: Area,
     attack: Attack,
     contact_type: ConnectionType,
+    counter: Option<CounterHit>,
 }
 
 #[derive(QueryData)]
@@ -69,7 +74,7 @@ pub(super) fn clash_parry(
         &Hitbox,
         &mut HitTracker,
         &Attack,
-        Option<&ProjectileMarker>,
+        Option<&mut Projectile>,
     )>,
     clock: Res<Clock>,
     mut owners: Query<&mut Gauges>,
@@ -77,7 +82,7 @@ pub(super) fn clash_parry(
 ) {
     let mut iter = hitboxes.iter_combinations_mut();
     while let Some(
-        [(owner1, gtf1, hitbox1, tracker1, attack1, maybe_proj1), (owner2, gtf2, hitbox2, tracker2, attack2, maybe_proj2)],
+        [(owner1, gtf1, hitbox1, tracker1, attack1, mut maybe_proj1), (owner2, gtf2, hitbox2, tracker2, attack2, mut maybe_proj2)],
     ) = iter.fetch_next()
     {
         if **owner1 == **owner2 {
@@ -110,9 +115,18 @@ pub(super) fn clash_parry(
                 None,
             ));
 
-            for (mut tracker, owner, is_projectile) in [
-                (tracker1, owner1, maybe_proj1.is_some()),
-                (tracker2, owner2, maybe_proj2.is_some()),
+            let (survives1, survives2) = match (&mut maybe_proj1, &mut maybe_proj2) {
+                (Some(proj1), Some(proj2)) => {
+                    (proj1.durability, proj2.durability) =
+                        clash_durability(proj1.durability, proj2.durability);
+                    (proj1.durability > 0, proj2.durability > 0)
+                }
+                _ => (false, false),
+            };
+
+            for (mut tracker, owner, is_projectile, survives) in [
+                (tracker1, owner1, maybe_proj1.is_some(), survives1),
+                (tracker2, owner2, maybe_proj2.is_some(), survives2),
             ] {
                 let mut properties = owners.get_mut(players.get(**owner)).unwrap();
 
@@ -125,7 +139,7 @@ pub(super) fn clash_parry(
                 }
 
                 // Despawn projectiles and consume hits
-                if tracker.hits >= 1 {
+                if tracker.hits >= 1 && !survives {
                     tracker.register_hit(clock.frame);
                 }
             }
@@ -133,11 +147,24 @@ pub(super) fn clash_parry(
     }
 }
 
+/// The sturdier projectile plows through with what it has left, equal ones cancel out
+fn clash_durability(first: usize, second: usize) -> (usize, usize) {
+    (first.saturating_sub(second), second.saturating_sub(first))
+}
+
 #[allow(clippy::type_complexity)]
 pub(super) fn detect_hits(
     clock: Res<Clock>,
+    rules: Res<MatchRules>,
     mut notifications: ResMut<Notifications>,
-    mut hitboxes: Query<(&Owner, &Attack, &Transform, &Hitbox, &mut HitTracker)>,
+    mut hitboxes: Query<(
+        &Owner,
+        &Attack,
+        &Transform,
+        &Hitbox,
+        &mut HitTracker,
+        Option<&ProjectileMarker>,
+    )>,
     players: Res<Players>,
     defenders: Query<(
         &Transform,
@@ -145,103 +172,143 @@ pub(super) fn detect_hits(
         &Hurtboxes,
         &PlayerState,
         &InputParser,
+        &Character,
     )>,
     attackers: Query<Option<&Combo>>,
 ) -> Vec<AttackConnection> {
+    // Players that have a move actively attacking, projectiles don't count
+    let active_attackers: Vec<Player> = hitboxes
+        .iter()
+        .filter(|(_, _, _, _, tracker, projectile)| {
+            projectile.is_none() && tracker.active(clock.frame)
+        })
+        .map(|(owner, ..)| **owner)
+        .collect();
+
     hitboxes
         .iter_mut()
-        .filter_map(|(hit_owner, attack, hitbox_tf, hitbox, mut hit_tracker)| {
-            if !hit_tracker.active(clock.frame) {
-                return None;
-            }
-
-            let attacking_player = **hit_owner;
-            let defending_player = hit_owner.other();
-
-            let defender = players.get(defending_player);
-            let attacker = players.get(**hit_owner);
-            let (defender_tf, facing, hurtboxes, state, parser) = defenders.get(defender).unwrap();
-            let combo = attackers.get(attacker).unwrap();
-
-            let offset_hitbox = hitbox.with_offset(hitbox_tf.translation.truncate());
-
-            // This technically doesn't get the actual overlap, as it just gets some overlap with one of the hitboxes
-            let overlap = hurtboxes.as_vec().iter().find_map(|hurtbox| {
-                // Different owners, hit can register
-                hurtbox
-                    .with_center(
-                        facing.visual.mirror_vec2(hurtbox.center())
-                            + defender_tf.translation.truncate(),
-                    )
-                    .intersection(&offset_hitbox)
-            })?;
-
-            if state.is_intangible() {
-                if !hit_tracker.hit_intangible {
-                    // Only send the notification once
-                    hit_tracker.hit_intangible = true;
-                    notifications.add(defending_player, "Intangible".to_owned());
+        .filter_map(
+            |(hit_owner, attack, hitbox_tf, hitbox, mut hit_tracker, _)| {
+                if !hit_tracker.active(clock.frame) {
+                    return None;
                 }
-                return None;
-            }
 
-            if hit_tracker.hits >= 1 {
-                hit_tracker.register_hit(clock.frame)
-            } else {
-                return None;
-            }
+                let attacking_player = **hit_owner;
+                let defending_player = hit_owner.other();
+
+                let defender = players.get(defending_player);
+                let attacker = players.get(**hit_owner);
+                let (defender_tf, facing, hurtboxes, state, parser, character) =
+                    defenders.get(defender).unwrap();
+                let combo = attackers.get(attacker).unwrap();
+
+                let overlap = hurtbox_overlap(
+                    hitbox.with_offset(hitbox_tf.translation.truncate()),
+                    hurtboxes,
+                    facing,
+                    defender_tf,
+                )?;
+
+                if state.is_intangible() {
+                    if !hit_tracker.hit_intangible {
+                        // Only send the notification once
+                        hit_tracker.hit_intangible = true;
+                        notifications.add(defending_player, "Intangible".to_owned());
+                    }
+                    return None;
+                }
 
-            // Connection confirmed
-
-            let (avoid_notification, contact_type) = match attack.to_hit.block_type {
-                BlockType::Strike(height) => {
-                    let parrying = state.has_flag(StatusFlag::Parry) && state.is_grounded();
-                    let (blocked, reason) = handle_blocking(
-                        height,
-                        facing.absolute.mirror_stick_pos(parser.get_stick_pos()),
-                    );
-
-                    if parrying {
-                        (Some("Parry!".into()), ConnectionType::Parry)
-                    } else if blocked && state.can_block() {
-                        (Some(reason), ConnectionType::Block)
-                    } else {
-                        (None, ConnectionType::Strike)
+                if matches!(attack.to_hit.block_type, BlockType::Strike(_))
+                    && !state.is_grounded()
+                    && combo.is_some_and(|combo| combo.out_of_juggle_points(&rules.combo))
+                {
+                    if !hit_tracker.hit_juggle_limit {
+                        // Only send the notification once
+                        hit_tracker.hit_juggle_limit = true;
+                        notifications.add(attacking_player, "Juggle limit".to_owned());
                     }
+                    return None;
+                }
+
+                if hit_tracker.hits >= 1 {
+                    hit_tracker.register_hit(clock.frame)
+                } else {
+                    return None;
                 }
-                BlockType::Grab => {
-                    if combo.is_some() {
-                        (
-                            Some("Can't grab from stun".into()),
-                            ConnectionType::Stunlock,
-                        )
-                    } else if yomi_teched(parser)
-                        && (state.can_block() && !state.action_in_progress())
-                    {
-                        (Some("Teched".into()), ConnectionType::Tech)
-                    } else {
-                        (None, ConnectionType::Throw)
+
+                // Connection confirmed
+
+                let (avoid_notification, contact_type) = match attack.to_hit.block_type {
+                    BlockType::Strike(height) => {
+                        let parrying = state.has_flag(StatusFlag::Parry) && state.is_grounded();
+                        let (blocked, reason) = handle_blocking(
+                            height,
+                            facing.absolute.mirror_stick_pos(parser.get_stick_pos()),
+                        );
+
+                        if parrying {
+                            (Some("Parry!".into()), ConnectionType::Parry)
+                        } else if blocked && state.can_block() {
+                            (Some(reason), ConnectionType::Block)
+                        } else if state
+                            .armor()
+                            .is_some_and(|armor| armor_covers(armor, height))
+                        {
+                            notifications.add(defending_player, "Armor".to_owned());
+                            (None, ConnectionType::Armor)
+                        } else {
+                            (None, ConnectionType::Strike)
+                        }
+                    }
+                    BlockType::Grab => {
+                        if combo.is_some() {
+                            (
+                                Some("Can't grab from stun".into()),
+                                ConnectionType::Stunlock,
+                            )
+                        } else {
+                            (None, ConnectionType::Throw)
+                        }
                     }
+                };
+
+                if let Some(reason) = avoid_notification {
+                    notifications.add(defending_player, format!("Avoid - {reason}"));
                 }
-            };
 
-            if let Some(reason) = avoid_notification {
-                notifications.add(defending_player, format!("Avoid - {reason}"));
-            }
+                if hit_tracker.hit_intangible {
+                    // Just a nice notification for now.
+                    notifications.add(attacking_player, "Meaty!".to_owned());
+                }
 
-            if hit_tracker.hit_intangible {
-                // Just a nice notification for now.
-                notifications.add(attacking_player, "Meaty!".to_owned());
-            }
+                // Throws check for this too, as counter hit throws can't be teched
+                let counter = match contact_type {
+                    ConnectionType::Strike | ConnectionType::Throw => {
+                        state.counter_hit(character, active_attackers.contains(&defending_player))
+                    }
+                    _ => None,
+                };
 
-            Some(AttackConnection {
-                defender,
-                attacker,
-                overlap,
-                attack: attack.to_owned(),
-                contact_type,
-            })
-        })
+                match counter {
+                    Some(CounterHit::Counter) => {
+                        notifications.add(attacking_player, "Counter hit!".to_owned())
+                    }
+                    Some(CounterHit::Punish) => {
+                        notifications.add(attacking_player, "Punish!".to_owned())
+                    }
+                    None => {}
+                }
+
+                Some(AttackConnection {
+                    defender,
+                    attacker,
+                    overlap,
+                    attack: attack.to_owned(),
+                    contact_type,
+                    counter,
+                })
+            },
+        )
         .collect()
 }
 
@@ -251,6 +318,8 @@ pub fn apply_connections(
     mut notifications: ResMut<Notifications>,
     mut players: Query<HitPlayerQuery>,
     abs_clock: Res<Clock>,
+    rules: Res<MatchRules>,
+    mut round_stats: ResMut<RoundStats>,
 ) {
     if hits.len() >= 2 {
         if hits
@@ -298,10 +367,12 @@ pub fn apply_connections(
         let avoided = match hit.contact_type {
             ConnectionType::Strike | ConnectionType::Throw => {
                 attacker.state.register_hit();
+                commands.trigger_targets(ItemTrigger::Hit, hit.attacker);
                 false
             }
             ConnectionType::Block => {
                 attacker.state.register_hit();
+                commands.trigger_targets(ItemTrigger::Block, hit.defender);
                 if defender.stats.defense_meter != 0 {
                     defender
                         .properties
@@ -312,7 +383,12 @@ pub fn apply_connections(
 
                 true
             }
-            ConnectionType::Tech | ConnectionType::Stunlock => true,
+            ConnectionType::Armor => {
+                attacker.state.register_hit();
+                defender.state.use_armor();
+                false
+            }
+            ConnectionType::Stunlock => true,
             ConnectionType::Parry => {
                 commands.trigger(ZoomCamera(0.3));
                 commands.trigger(SoundRequest::from(Sound::Clash));
@@ -330,6 +406,7 @@ pub fn apply_connections(
                 ));
 
                 defender.state.register_hit();
+                commands.trigger_targets(ItemTrigger::Parry, hit.defender);
 
                 return;
             }
@@ -356,10 +433,14 @@ pub fn apply_connections(
                 airborne: !defender.state.is_grounded(),
                 hitbox_pos: hit.overlap.center(),
                 defender_stats: *defender.stats,
+                counter: hit.counter,
             },
         );
 
-        if !avoided {
+        if hit.contact_type == ConnectionType::Armor {
+            attacker_actions = absorb_with_armor(attacker_actions, false);
+            defender_actions = absorb_with_armor(defender_actions, true);
+        } else if !avoided {
             if attacker.combo.ongoing() {
                 attacker.combo.hits += 1;
             } else {
@@ -370,6 +451,8 @@ pub fn apply_connections(
 
                 commands.trigger(SoundRequest::from(Sound::Matches));
                 notifications.add(*attacker.player, "Opener!".to_owned());
+                round_stats.register_hit(*attacker.player);
+                commands.trigger_targets(ItemTrigger::Opener, hit.attacker);
                 if attacker.stats.opener_damage_multiplier > 1.0 {
                     attacker_actions = handle_opener(attacker_actions, attacker.stats);
                     attacker_actions.push(ActionEvent::ModifyResource(
@@ -380,6 +463,20 @@ pub fn apply_connections(
                 }
             }
 
+            if defender_actions
+                .iter()
+                .any(|action| matches!(action, ActionEvent::LaunchStun(_)))
+            {
+                attacker.combo.juggle_points += hit.attack.to_hit.juggle_cost;
+            }
+
+            if let Some(decay) = rules.combo.hitstun_decay {
+                if hit.attack.to_hit.hitstun_decay {
+                    defender_actions =
+                        apply_hitstun_decay(defender_actions, decay, attacker.combo.hits);
+                }
+            }
+
             // This may break throws
             if defender.stats.direct_influence > 0.0 {
                 defender.velocity.add_impulse(
@@ -402,6 +499,22 @@ pub fn apply_connections(
     }
 }
 
+// This technically doesn't get the actual overlap, as it just gets some overlap with one of the hitboxes
+pub(super) fn hurtbox_overlap(
+    offset_hitbox: Area,
+    hurtboxes: &Hurtboxes,
+    facing: &CharacterFacing,
+    defender_tf: &Transform,
+) -> Option<Area> {
+    hurtboxes.as_vec().iter().find_map(|hurtbox| {
+        hurtbox
+            .with_center(
+                facing.visual.mirror_vec2(hurtbox.center()) + defender_tf.translation.truncate(),
+            )
+            .intersection(&offset_hitbox)
+    })
+}
+
 fn handle_blocking(height: AttackHeight, stick: StickPosition) -> (bool, String) {
     let blocking_high = stick == StickPosition::W;
     let blocking_low = stick == StickPosition::SW;
@@ -419,8 +532,43 @@ fn handle_blocking(height: AttackHeight, stick: StickPosition) -> (bool, String)
     }
 }
 
-fn yomi_teched(parser: &InputParser) -> bool {
-    parser.head_is_clear()
+fn armor_covers(armor: Armor, height: AttackHeight) -> bool {
+    match height {
+        AttackHeight::Low => armor.low,
+        AttackHeight::Mid => armor.mid,
+        AttackHeight::High => armor.high,
+    }
+}
+
+// The defender takes reduced damage and doesn't get stunned or moved, hitstop is shorter for both
+fn absorb_with_armor(actions: Vec<ActionEvent>, defender: bool) -> Vec<ActionEvent> {
+    actions
+        .into_iter()
+        .filter_map(|action| match action {
+            ActionEvent::Hitstop(_) => Some(ActionEvent::Hitstop(ON_ARMOR_HITSTOP)),
+            ActionEvent::ModifyResource(GaugeType::Health, amount) if defender => {
+                Some(ActionEvent::ModifyResource(
+                    GaugeType::Health,
+                    (amount as f32 * ARMOR_DAMAGE_MULTIPLIER) as i32,
+                ))
+            }
+            ActionEvent::HitStun(_)
+            | ActionEvent::BlockStun(_)
+            | ActionEvent::LaunchStun(_)
+            | ActionEvent::Movement(_)
+            | ActionEvent::MultiplyMomentum(_)
+            | ActionEvent::ForceStand
+            | ActionEvent::ForceCrouch
+            | ActionEvent::ForceAir
+            | ActionEvent::StartAction(_)
+            | ActionEvent::SnapToOpponent { .. }
+                if defender =>
+            {
+                None
+            }
+            other => Some(other),
+        })
+        .collect()
 }
 
 fn handle_opener(actions: Vec<ActionEvent>, status_effect: &Stats) -> Vec<ActionEvent> {
@@ -438,6 +586,21 @@ fn handle_opener(actions: Vec<ActionEvent>, status_effect: &Stats) -> Vec<Action
         })
         .collect()
 }
+
+fn apply_hitstun_decay(
+    actions: Vec<ActionEvent>,
+    decay: HitstunDecay,
+    combo_hits: usize,
+) -> Vec<ActionEvent> {
+    actions
+        .into_iter()
+        .map(|action| match action {
+            ActionEvent::HitStun(amount) => ActionEvent::HitStun(decay.apply(amount, combo_hits)),
+            other => other,
+        })
+        .collect()
+}
+
 fn apply_damage_multiplier(actions: Vec<ActionEvent>, multiplier: f32) -> Vec<ActionEvent> {
     actions
         .into_iter()
@@ -486,6 +649,53 @@ pub fn snap_and_switch(
     }
 }
 
+pub fn throw_tech(
+    trigger: Trigger<ThrowTech>,
+    mut commands: Commands,
+    mut notifications: ResMut<Notifications>,
+    mut round_stats: ResMut<RoundStats>,
+    players: Res<Players>,
+    mut query: Query<(
+        &Player,
+        &Transform,
+        &CharacterFacing,
+        &mut PlayerState,
+        &mut PlayerVelocity,
+    )>,
+) {
+    let entities = [trigger.target(), players.get_other_entity(trigger.target())];
+    let mut midpoint = Vec3::ZERO;
+
+    for (entity, (player, tf, facing, mut state, mut velocity)) in entities
+        .into_iter()
+        .zip(query.get_many_mut(entities).unwrap())
+    {
+        // Locks from the throw would keep them in place
+        state.clear_conditions(StatusFlag::MovementLock);
+        velocity.add_impulse(facing.absolute.mirror_vec2(Vec2::X * -THROW_TECH_PUSHBACK));
+        commands.trigger_targets(EndAction, entity);
+        notifications.add(*player, "Throw tech".to_owned());
+        if entity == trigger.target() {
+            round_stats.register_throw_tech(*player);
+        }
+        midpoint += tf.translation * 0.5;
+    }
+
+    commands.trigger(SoundRequest::throw_tech());
+    commands.trigger(SpawnVfx(
+        VfxRequest {
+            effect: VisualEffect::RingPulse(RingPulse {
+                base_color: THROW_TECH_RING_BASE_COLOR,
+                edge_color: THROW_TECH_RING_EDGE_COLOR,
+                ..default()
+            }),
+            tf: Transform::from_translation(midpoint + Vec3::Y),
+            ..default()
+        },
+        None,
+    ));
+}
+
 pub fn hitstun_events(
     trigger: Trigger<UpdateHitstun>,
     mut query: Query<(&mut PlayerState, &CharacterClock)>,
@@ -510,3 +720,15 @@ pub fn launch_events(
     state.launch();
     velocity.add_impulse(facing.visual.mirror_vec2(trigger.event().0));
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sturdier_projectile_survives_clash() {
+        assert_eq!(clash_durability(3, 1), (2, 0));
+        assert_eq!(clash_durability(1, 3), (0, 2));
+        assert_eq!(clash_durability(2, 2), (0, 0));
+    }
+}
@@ -4,12 +4,14 @@ mod hit_tracker;
 mod hitboxes;
 mod hitreg;
 mod hitstop;
+mod projectiles;
 
 pub use hitboxes::{spawn_hitbox, LifetimeFlags, ProjectileMarker};
-pub use hitreg::{blockstun_events, hitstun_events, launch_events, snap_and_switch};
+pub use hitreg::{blockstun_events, hitstun_events, launch_events, snap_and_switch, throw_tech};
 
 pub use hit_tracker::HitTracker;
 pub use hitboxes::HitboxSpawner;
+pub use projectiles::Projectile;
 
 use foundation::{RollbackSchedule, SystemStep};
 pub struct DamagePlugin;
@@ -19,6 +21,10 @@ impl Plugin for DamagePlugin {
         app.add_systems(
             RollbackSchedule,
             (
+                // Steering is here rather than in movement as it needs to know who owns what
+                projectiles::steer_projectiles,
+                projectiles::guard_projectiles,
+                crate::puppet_management::hit_puppets,
                 hitreg::clash_parry,
                 hitreg::detect_hits.pipe(hitreg::apply_connections),
                 hitboxes::handle_despawn_flags,
@@ -0,0 +1,229 @@
+use bevy::prelude::*;
+
+use characters::{Hitbox, Hurtboxes, ProjectileBehaviour};
+use foundation::{CharacterFacing, Clock, Owner, Player, Players, ProjectileGuard, FPS};
+use player_state::PlayerState;
+
+use crate::{entity_management::DespawnMarker, movement::ObjectVelocity, ui::Notifications};
+
+use super::{hitreg::hurtbox_overlap, HitTracker};
+
+// Aim for the chest, not the feet
+const PROJECTILE_AIM_HEIGHT: f32 = 1.0;
+// Close enough to catch a returning boomerang
+const BOOMERANG_CATCH_DISTANCE: f32 = 0.5;
+
+#[derive(Debug, Component, Clone, Copy)]
+pub struct Projectile {
+    pub behaviour: ProjectileBehaviour,
+    pub durability: usize,
+    pub spawn_frame: usize,
+}
+
+pub(super) fn steer_projectiles(
+    mut commands: Commands,
+    clock: Res<Clock>,
+    players: Query<(&Transform, &Player)>,
+    mut projectiles: Query<(Entity, &Projectile, &Owner, &Transform, &mut ObjectVelocity)>,
+) {
+    for (entity, projectile, owner, tf, mut velocity) in &mut projectiles {
+        let target_player = match projectile.behaviour {
+            ProjectileBehaviour::Homing(_) => owner.other(),
+            ProjectileBehaviour::Boomerang(_) => **owner,
+            _ => continue,
+        };
+
+        let Some(target) = players
+            .iter()
+            .find(|(_, player)| **player == target_player)
+            .map(|(player_tf, _)| player_tf.translation + Vec3::Y * PROJECTILE_AIM_HEIGHT)
+        else {
+            continue;
+        };
+        let to_target = target - tf.translation;
+
+        match projectile.behaviour {
+            ProjectileBehaviour::Homing(turn_rate) => {
+                velocity.speed = turn_towards(velocity.speed, to_target, turn_rate / FPS);
+            }
+            ProjectileBehaviour::Boomerang(return_after) => {
+                if clock.frame < projectile.spawn_frame + return_after {
+                    continue;
+                }
+
+                if return_boomerang(&mut velocity, to_target) {
+                    commands.entity(entity).insert(DespawnMarker(0));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Heads straight for the owner, true once it's close enough to catch
+fn return_boomerang(velocity: &mut ObjectVelocity, to_owner: Vec3) -> bool {
+    if to_owner.length() < BOOMERANG_CATCH_DISTANCE {
+        return true;
+    }
+
+    velocity.speed = to_owner.normalize_or_zero() * velocity.speed.length();
+    velocity.acceleration = Vec3::ZERO;
+    false
+}
+
+fn turn_towards(speed: Vec3, direction: Vec3, max_angle: f32) -> Vec3 {
+    let current = speed.truncate();
+    // There is no angle to a zero vector
+    if current == Vec2::ZERO || direction.truncate() == Vec2::ZERO {
+        return speed;
+    }
+
+    let angle = current
+        .angle_to(direction.truncate())
+        .clamp(-max_angle, max_angle);
+    Vec2::from_angle(angle).rotate(current).extend(speed.z)
+}
+
+#[allow(clippy::type_complexity)]
+pub(super) fn guard_projectiles(
+    clock: Res<Clock>,
+    players: Res<Players>,
+    mut notifications: ResMut<Notifications>,
+    defenders: Query<(&Transform, &CharacterFacing, &Hurtboxes, &PlayerState)>,
+    mut projectiles: Query<
+        (
+            &mut Owner,
+            &Transform,
+            &Hitbox,
+            &mut HitTracker,
+            Option<&mut ObjectVelocity>,
+        ),
+        With<Projectile>,
+    >,
+) {
+    for (mut owner, tf, hitbox, mut tracker, mut maybe_velocity) in &mut projectiles {
+        if !tracker.active(clock.frame) {
+            continue;
+        }
+
+        let defending_player = owner.other();
+        let (defender_tf, facing, hurtboxes, state) =
+            defenders.get(players.get(defending_player)).unwrap();
+
+        let Some(guard) = state.projectile_guard() else {
+            continue;
+        };
+
+        if hurtbox_overlap(
+            hitbox.with_offset(tf.translation.truncate()),
+            hurtboxes,
+            facing,
+            defender_tf,
+        )
+        .is_none()
+        {
+            continue;
+        }
+
+        apply_guard(
+            guard,
+            &mut owner,
+            &mut tracker,
+            maybe_velocity.as_deref_mut(),
+        );
+        notifications.add(
+            defending_player,
+            match guard {
+                ProjectileGuard::Reflect => "Reflected!",
+                ProjectileGuard::Absorb => "Absorbed!",
+            }
+            .to_owned(),
+        );
+    }
+}
+
+fn apply_guard(
+    guard: ProjectileGuard,
+    owner: &mut Owner,
+    tracker: &mut HitTracker,
+    velocity: Option<&mut ObjectVelocity>,
+) {
+    match guard {
+        ProjectileGuard::Reflect => {
+            *owner = Owner(owner.other());
+            if let Some(velocity) = velocity {
+                velocity.speed.x = -velocity.speed.x;
+                velocity.acceleration.x = -velocity.acceleration.x;
+            }
+        }
+        ProjectileGuard::Absorb => {
+            // Gets despawned along with the other spent hitboxes
+            tracker.hits = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn homing_turns_gradually() {
+        let speed = Vec3::X * 2.0;
+
+        let turned = turn_towards(speed, Vec3::Y, PI / 4.0);
+        assert!((turned.length() - 2.0).abs() < 0.001);
+        assert!((turned.truncate().to_angle() - PI / 4.0).abs() < 0.001);
+
+        let aligned = turn_towards(speed, Vec3::new(1.0, 0.1, 0.0), PI);
+        assert!(
+            (aligned.truncate().normalize() - Vec2::new(1.0, 0.1).normalize()).length() < 0.001
+        );
+
+        // A projectile that has stopped or is on top of the target stays put
+        assert_eq!(turn_towards(Vec3::ZERO, Vec3::Y, PI), Vec3::ZERO);
+        assert_eq!(turn_towards(speed, Vec3::ZERO, PI), speed);
+    }
+
+    #[test]
+    fn boomerang_returns_and_gets_caught() {
+        let mut velocity = ObjectVelocity::new(Vec3::X * 3.0, 1.0);
+
+        assert!(!return_boomerang(&mut velocity, Vec3::new(-4.0, 0.0, 0.0)));
+        assert!((velocity.speed - Vec3::X * -3.0).length() < 0.001);
+        assert_eq!(velocity.acceleration, Vec3::ZERO);
+
+        assert!(return_boomerang(&mut velocity, Vec3::X * 0.1));
+    }
+
+    #[test]
+    fn reflecting_changes_owner_and_direction() {
+        let mut owner = Owner(Player::One);
+        let mut tracker = HitTracker::new(1);
+        let mut velocity = ObjectVelocity::new(Vec3::new(2.0, 1.0, 0.0), 0.0);
+        velocity.acceleration.x = 0.5;
+
+        apply_guard(
+            ProjectileGuard::Reflect,
+            &mut owner,
+            &mut tracker,
+            Some(&mut velocity),
+        );
+        assert_eq!(*owner, Player::Two);
+        assert_eq!(velocity.speed, Vec3::new(-2.0, 1.0, 0.0));
+        assert_eq!(velocity.acceleration.x, -0.5);
+        assert_eq!(tracker.hits, 1);
+    }
+
+    #[test]
+    fn absorbing_spends_the_projectile() {
+        let mut owner = Owner(Player::One);
+        let mut tracker = HitTracker::new(2);
+
+        apply_guard(ProjectileGuard::Absorb, &mut owner, &mut tracker, None);
+        assert_eq!(*owner, Player::One);
+        assert_eq!(tracker.hits, 0);
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use foundation::{ActionId, Player};
+
+use crate::player_state_management::ActivationReport;
+
+// How many interesting frames are kept on screen per player
+const HISTORY_LENGTH: usize = 8;
+
+#[derive(Debug, Component)]
+pub(super) struct InputDebugOverlay;
+
+#[derive(Debug, Default)]
+pub(super) struct InputDebugHistory {
+    lines: HashMap<Player, VecDeque<String>>,
+    last_frame: HashMap<Player, usize>,
+}
+
+pub(super) fn setup_input_debug_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(15.0),
+            left: Val::Percent(1.0),
+            ..default()
+        },
+        Text::default(),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Visibility::Hidden,
+        GlobalZIndex(100),
+        InputDebugOverlay,
+        Name::new("Input debug overlay"),
+    ));
+}
+
+pub(super) fn toggle_input_debug_overlay(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay: Query<&mut Visibility, With<InputDebugOverlay>>,
+) {
+    if keys.just_pressed(KeyCode::Digit8) {
+        info!("Input debug overlay toggle");
+        for mut visibility in &mut overlay {
+            visibility.toggle_visible_hidden();
+        }
+    }
+}
+
+pub(super) fn report_move_activations(
+    mut history: Local<InputDebugHistory>,
+    reports: Query<(&Player, &ActivationReport)>,
+    mut overlay: Query<&mut Text, With<InputDebugOverlay>>,
+) {
+    for (player, report) in &reports {
+        if history.last_frame.get(player) == Some(&report.frame) {
+            continue;
+        }
+        history.last_frame.insert(*player, report.frame);
+
+        if report.parsed.is_empty() && report.rejected.is_empty() && report.activated.is_none() {
+            continue;
+        }
+
+        debug!(
+            %player,
+            frame = report.frame,
+            parsed = ?report.parsed,
+            buffered = ?report.buffered,
+            rejected = ?report.rejected,
+            activated = ?report.activated,
+            in_hitstop = report.in_hitstop,
+            "Move activation"
+        );
+
+        let lines = history.lines.entry(*player).or_default();
+        lines.push_front(format_report(report));
+        lines.truncate(HISTORY_LENGTH);
+    }
+
+    let Ok(mut text) = overlay.single_mut() else {
+        return;
+    };
+
+    text.0 = [Player::One, Player::Two]
+        .into_iter()
+        .filter_map(|player| {
+            let lines = history.lines.get(&player)?;
+            Some(format!(
+                "{player}\n{}",
+                lines.iter().cloned().collect::<Vec<_>>().join("\n")
+            ))
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+}
+
+fn format_report(report: &ActivationReport) -> String {
+    let mut out = format!("[{}]", report.frame);
+
+    if !report.parsed.is_empty() {
+        out += &format!(" parsed: {}", format_ids(&report.parsed));
+    }
+
+    if !report.buffered.is_empty() {
+        out += &format!(" buffered: {}", format_ids(&report.buffered));
+    }
+
+    if report.in_hitstop {
+        out += " (hitstop)";
+    }
+
+    if let Some(id) = report.activated {
+        out += &format!(" -> {id:?}");
+    }
+
+    for (id, reason) in &report.rejected {
+        out += &format!("\n    {id:?} rejected: {reason}");
+    }
+
+    out
+}
+
+fn format_ids(ids: &[ActionId]) -> String {
+    ids.iter()
+        .map(|id| format!("{id:?}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
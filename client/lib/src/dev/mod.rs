@@ -21,6 +21,7 @@ use crate::{
 };
 
 mod box_visualization;
+mod input_debug;
 
 pub struct DevPlugin;
 
@@ -46,7 +47,10 @@ impl Plugin for DevPlugin {
         .register_type::<Stats>()
         .register_type::<InputParser>()
         .register_type::<ParrotStream>()
-        .add_systems(Startup, setup_gizmos)
+        .add_systems(
+            Startup,
+            (setup_gizmos, input_debug::setup_input_debug_overlay),
+        )
         // This needs access to gamepads, which don't in startup
         // It also needs to happen before any other gameplay systems
         // Too early, dev-local breaks. Too late and dev-synctest breaks.
@@ -71,6 +75,8 @@ impl Plugin for DevPlugin {
                 box_visualization::visualize_hurtboxes,
                 box_visualization::visualize_pushboxes,
                 box_visualization::visualize_generic_areas,
+                input_debug::toggle_input_debug_overlay,
+                input_debug::report_move_activations,
             ),
         );
     }
@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use characters::{ActionEvent, Attack, GaugeType};
 
-use foundation::{ActionId, Area, SimpleState, StatusFlag, VfxRequest, VoiceLine};
+use foundation::{ActionId, Area, ItemId, SimpleState, StatusFlag, VfxRequest, VoiceLine};
 
 #[derive(Debug, Event)]
 pub struct StartAction(pub ActionId);
@@ -30,6 +30,9 @@ pub struct SnapToOpponent {
     pub sideswitch: bool,
 }
 
+#[derive(Debug, Event)]
+pub struct ThrowTech;
+
 #[derive(Debug, Event)]
 pub struct UpdateHitstun(pub usize);
 
@@ -84,6 +87,15 @@ pub struct ClearStatus(pub StatusFlag);
 #[derive(Debug, Event)]
 pub struct FlipVisuals;
 
+#[derive(Debug, Event)]
+pub struct CommandPuppets(pub ActionId);
+
+#[derive(Debug, Event)]
+pub struct DespawnPuppets;
+
+#[derive(Debug, Event)]
+pub struct ConsumeItem(pub ItemId);
+
 pub fn spread_events(trigger: Trigger<ActionEvent>, mut commands: Commands) {
     match trigger.event() {
         ActionEvent::Animation(ar) => {
@@ -136,6 +148,9 @@ pub fn spread_events(trigger: Trigger<ActionEvent>, mut commands: Commands) {
                 trigger.target(),
             );
         }
+        ActionEvent::ThrowTech => {
+            commands.trigger_targets(ThrowTech, trigger.target());
+        }
         // TODO: Maybe these could be compressed to one event that contains a struct?
         ActionEvent::HitStun(hs) => {
             commands.trigger_targets(UpdateHitstun(*hs), trigger.target());
@@ -197,6 +212,18 @@ pub fn spread_events(trigger: Trigger<ActionEvent>, mut commands: Commands) {
         ActionEvent::SpawnPickup(pickup_request) => {
             commands.trigger_targets(*pickup_request, trigger.target())
         }
+        ActionEvent::SpawnPuppet(puppet_request) => {
+            commands.trigger_targets(*puppet_request, trigger.target())
+        }
+        ActionEvent::CommandPuppets(action_id) => {
+            commands.trigger_targets(CommandPuppets(*action_id), trigger.target());
+        }
+        ActionEvent::DespawnPuppets => {
+            commands.trigger_targets(DespawnPuppets, trigger.target());
+        }
+        ActionEvent::ConsumeItem(item_id) => {
+            commands.trigger_targets(ConsumeItem(*item_id), trigger.target());
+        }
         ActionEvent::FlipVisuals => {
             commands.trigger_targets(FlipVisuals, trigger.target());
         }
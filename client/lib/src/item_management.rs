@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use characters::{Character, Gauges, Inventory, ItemTrigger};
+use foundation::{CharacterClock, CharacterFacing, Clock, Combo, Player, Stats};
+use input_parsing::InputParser;
+use player_state::PlayerState;
+
+use crate::{event_spreading::ConsumeItem, ui::Notifications};
+
+#[allow(clippy::type_complexity)]
+pub fn fire_item_triggers(
+    trigger: Trigger<ItemTrigger>,
+    mut commands: Commands,
+    abs_clock: Res<Clock>,
+    query: Query<(
+        &PlayerState,
+        &Character,
+        &Inventory,
+        &Gauges,
+        &InputParser,
+        &Stats,
+        &CharacterClock,
+        &Transform,
+        &CharacterFacing,
+        &Combo,
+    )>,
+) {
+    let (state, character, inventory, gauges, parser, stats, clock, tf, facing, combo) =
+        query.get(trigger.target()).unwrap();
+
+    let situation = state.build_situation(
+        inventory.to_owned(),
+        gauges.to_owned(),
+        parser.to_owned(),
+        stats.to_owned(),
+        clock.frame,
+        abs_clock.frame,
+        tf.translation,
+        *facing,
+        *combo,
+    );
+
+    for event in inventory.triggered_events(character, *trigger.event(), &situation) {
+        commands.trigger_targets(event, trigger.target());
+    }
+}
+
+pub fn consume_items(
+    trigger: Trigger<ConsumeItem>,
+    mut notifications: ResMut<Notifications>,
+    mut query: Query<(&Player, &Character, &mut Inventory)>,
+) {
+    let (player, character, mut inventory) = query.get_mut(trigger.target()).unwrap();
+    let ConsumeItem(item_id) = *trigger.event();
+
+    inventory.consume(item_id);
+    if let Some(item) = character
+        .items
+        .get(&item_id)
+        .filter(|_| !inventory.contains(item_id))
+    {
+        notifications.add(*player, format!("Out of {}", item.name.to_lowercase()));
+    }
+}
@@ -7,17 +7,19 @@ mod damage;
 mod dev;
 mod entity_management;
 mod event_spreading;
+mod item_management;
 mod movement;
 mod networking;
 mod pickup_management;
 mod player_state_management;
+mod puppet_management;
 mod resources;
 mod stage;
 mod state_transitions;
 mod ui;
 
 use bevy::{app::PluginGroupBuilder, prelude::*};
-use foundation::WagArgs;
+use foundation::{BuildOrders, StickConfig, WagArgs};
 
 // Only thing exported out of this crate
 #[derive(Debug)]
@@ -50,6 +52,7 @@ impl PluginGroup for Lib {
             .add(state_transitions::StateTransitionPlugin)
             .add(networking::NetworkPlugin)
             .add(pickup_management::PickupPlugin)
+            .add(puppet_management::PuppetPlugin)
             .add(entity_management::EntityManagementPlugin);
 
         if self.args.dev.is_some() {
@@ -70,6 +73,12 @@ impl ArgsPlugin {
 }
 impl Plugin for ArgsPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(self.args.clone());
+        app.insert_resource(self.args.clone())
+            .insert_resource(StickConfig {
+                default: self.args.stick_settings(),
+                ..default()
+            })
+            .insert_resource(self.args.match_rules().seeded())
+            .insert_resource(BuildOrders::load(&self.args.build_orders_file));
     }
 }
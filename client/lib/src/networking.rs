@@ -9,19 +9,22 @@ use bevy_ggrs::*;
 use bevy_matchbox::prelude::*;
 use characters::{Attack, Gauges, Hitbox, Hurtboxes, Inventory};
 use foundation::{
-    Area, CharacterClock, CharacterFacing, Characters, Clock, Combo, Controllers, GameState,
-    InputDevice, InputStream, LocalCharacter, LocalController, MatchState, NetworkInputButton,
-    OnlineState, Owner, Pickup, Player, RollbackSchedule, RoundLog, Stats, WagArgs,
-    STICK_DEAD_ZONE,
+    Area, CharacterClock, CharacterFacing, Characters, Clock, Combo, ControlScheme, ControlSchemes,
+    Controllers, EconomyLedger, GameState, InputDevice, InputStream, InputTiming, InputTimings,
+    LocalCharacter, LocalController, MatchRules, MatchState, NetworkInputButton, OnlineState,
+    Owner, Palettes, Pickup, Player, RollbackSchedule, RoundLog, RoundStats, Stats, StickConfig,
+    StickSettings, WagArgs,
 };
 use input_parsing::{InputParser, ParrotStream};
-use player_state::PlayerState;
+use player_state::{PlayerState, PuppetState};
 use strum::IntoEnumIterator;
 
 use crate::{
-    assets::{AnimationHelper, AnimationHelperSetup, CharacterShake, ExtendedFlashMaterial},
+    assets::{
+        AnimationHelper, AnimationHelperSetup, CharacterData, CharacterShake, ExtendedFlashMaterial,
+    },
     camera::{ChildCameraEffects, RootCameraEffects},
-    damage::{HitTracker, HitboxSpawner, LifetimeFlags, ProjectileMarker},
+    damage::{HitTracker, HitboxSpawner, LifetimeFlags, Projectile, ProjectileMarker},
     entity_management::DespawnMarker,
     movement::{Follow, ObjectVelocity, PlayerVelocity, Pushbox, Walls},
     player_state_management::MoveBuffer,
@@ -80,6 +83,8 @@ impl Plugin for NetworkPlugin {
             // Resources
             .rollback_resource_with_clone::<InputStream>()
             .rollback_resource_with_clone::<RoundLog>()
+            .rollback_resource_with_clone::<RoundStats>()
+            .rollback_resource_with_clone::<EconomyLedger>()
             .rollback_resource_with_copy::<Clock>()
             .rollback_resource_with_copy::<Walls>()
             // Player components
@@ -110,7 +115,9 @@ impl Plugin for NetworkPlugin {
             .rollback_component_with_copy::<LifetimeFlags>()
             .rollback_component_with_copy::<ObjectVelocity>()
             .rollback_component_with_copy::<Owner>()
+            .rollback_component_with_copy::<Projectile>()
             .rollback_component_with_copy::<ProjectileMarker>()
+            .rollback_component_with_copy::<PuppetState>()
             // Pickups
             .rollback_component_with_copy::<Area>()
             .rollback_component_with_copy::<Pickup>()
@@ -161,6 +168,7 @@ pub fn network_teardown(commands: &mut Commands) {
     commands.remove_resource::<Controllers>();
     commands.remove_resource::<LocalCharacter>();
     commands.remove_resource::<LocalController>();
+    commands.remove_resource::<InputTimings>();
 }
 
 #[derive(Debug, Default)]
@@ -181,6 +189,10 @@ fn wait_for_players(
     args: Res<WagArgs>,
     mut next_game_state: ResMut<NextState<GameState>>,
     mut next_match_state: ResMut<NextState<MatchState>>,
+    schemes: Res<ControlSchemes>,
+    palettes: Res<Palettes>,
+    rules: Res<MatchRules>,
+    character_data: CharacterData,
 ) {
     match &mut *connection_state {
         ConnectionState::WaitingToEstablish => {
@@ -203,9 +215,23 @@ fn wait_for_players(
                 })
                 .unwrap();
 
-            socket
-                .channel_mut(0)
-                .send(Box::new([local_character.0.into()]), peer);
+            // Online character select always uses the player one slot
+            let header = [
+                local_character.0.into(),
+                args.input_timing.into(),
+                schemes.p1.into(),
+                palettes.p1 as u8,
+            ];
+            let data_hash = character_data.data_hash().to_le_bytes();
+            socket.channel_mut(0).send(
+                // Rules take the rest of the message
+                header
+                    .into_iter()
+                    .chain(data_hash)
+                    .chain(rules.to_bytes())
+                    .collect(),
+                peer,
+            );
 
             let contents = loop {
                 let data = socket.channel_mut(0).receive();
@@ -216,8 +242,50 @@ fn wait_for_players(
                 break data[0].1.clone();
             };
 
+            if contents.get(4..12) != Some(&data_hash[..]) {
+                error!("Character or item files differ from the other player's, leaving");
+                network_teardown(&mut commands);
+                next_game_state.set(GameState::MainMenu);
+                *connection_state = ConnectionState::default();
+                return;
+            }
+
+            let Some(remote_timing) = contents.get(1).copied().map(InputTiming::from) else {
+                error!("Character sync message is missing the input timing, leaving");
+                network_teardown(&mut commands);
+                next_game_state.set(GameState::MainMenu);
+                *connection_state = ConnectionState::default();
+                return;
+            };
+            let remote_scheme = contents
+                .get(2)
+                .copied()
+                .map(ControlScheme::from)
+                .unwrap_or_else(|| {
+                    warn!("Character sync message is missing the control scheme, using default");
+                    ControlScheme::default()
+                });
+            let remote_palette = contents.get(3).map_or_else(
+                || {
+                    warn!("Character sync message is missing the palette, using the first one");
+                    0
+                },
+                |&palette| palette as usize,
+            );
+
+            // Player one's rules are used
+            let synced_rules = if peer_index == 0 {
+                MatchRules::from_bytes(&contents[12..]).unwrap_or_else(|| {
+                    warn!("Couldn't read the rules of player one, using local ones");
+                    rules.clone()
+                })
+            } else {
+                rules.clone()
+            };
+
             // First to join is index 0 -> player 1
-            let (chars, controllers) = if peer_index == 0 {
+            let (chars, controllers, timings, synced_schemes, synced_palettes) = if peer_index == 0
+            {
                 (
                     Characters {
                         p1: contents[0].into(),
@@ -227,6 +295,18 @@ fn wait_for_players(
                         p1: InputDevice::Online(0),
                         p2: local_controls.0,
                     },
+                    InputTimings {
+                        p1: remote_timing,
+                        p2: args.input_timing,
+                    },
+                    ControlSchemes {
+                        p1: remote_scheme,
+                        p2: schemes.p1,
+                    },
+                    Palettes {
+                        p1: remote_palette,
+                        p2: palettes.p1,
+                    },
                 )
             } else if peer_index == 1 {
                 (
@@ -238,6 +318,18 @@ fn wait_for_players(
                         p1: local_controls.0,
                         p2: InputDevice::Online(1),
                     },
+                    InputTimings {
+                        p1: args.input_timing,
+                        p2: remote_timing,
+                    },
+                    ControlSchemes {
+                        p1: schemes.p1,
+                        p2: remote_scheme,
+                    },
+                    Palettes {
+                        p1: palettes.p1,
+                        p2: remote_palette,
+                    },
                 )
             } else {
                 // I'm assuming only valid indices are 0 and 1
@@ -248,6 +340,10 @@ fn wait_for_players(
 
             commands.insert_resource(chars);
             commands.insert_resource(controllers);
+            commands.insert_resource(timings);
+            commands.insert_resource(synced_schemes);
+            commands.insert_resource(synced_palettes);
+            commands.insert_resource(synced_rules);
             *connection_state = ConnectionState::StartSession;
         }
         ConnectionState::StartSession => {
@@ -305,6 +401,7 @@ fn read_local_inputs(
     maybe_controller: Option<Res<LocalController>>,
     local_players: Res<LocalPlayers>,
     pad_query: Query<&Gamepad>,
+    stick_config: Res<StickConfig>,
 ) {
     let Some(local_controls) = maybe_controller else {
         return;
@@ -323,32 +420,15 @@ fn read_local_inputs(
                 gamepad.pressed(nw_btn.to_gamepad_button_type())
             });
 
-            if let (Some(stick_x), Some(stick_y)) = (
-                gamepad.get(GamepadAxis::LeftStickX),
-                gamepad.get(GamepadAxis::LeftStickY),
-            ) {
-                let (up, down) = if stick_y.abs() < STICK_DEAD_ZONE {
-                    (0, 0)
-                } else if stick_y < 0.0 {
-                    (0, 1)
-                } else {
-                    (1, 0)
-                };
-
-                let (left, right) = if stick_x.abs() < STICK_DEAD_ZONE {
-                    (0, 0)
-                } else if stick_x < 0.0 {
-                    (1, 0)
-                } else {
-                    (0, 1)
-                };
-
-                // You may end up with opposing cardinals pressed with stick+dpad
-                input |= up;
-                input |= down << 1;
-                input |= left << 2;
-                input |= right << 3;
-            }
+            let stick = read_analog_stick(gamepad, stick_config.get(local_controls.0));
+            let (up, down) = (u16::from(stick.y > 0), u16::from(stick.y < 0));
+            let (left, right) = (u16::from(stick.x < 0), u16::from(stick.x > 0));
+
+            // You may end up with opposing cardinals pressed with stick+dpad
+            input |= up;
+            input |= down << 1;
+            input |= left << 2;
+            input |= right << 3;
         }
         InputDevice::Keyboard => {
             input |= NetworkInputButton::serialize(|nw_btn: NetworkInputButton| {
@@ -367,10 +447,24 @@ fn read_local_inputs(
     commands.insert_resource(LocalInputs::<Config>(inputs));
 }
 
+fn read_analog_stick(pad: &Gamepad, settings: StickSettings) -> IVec2 {
+    let Some((x_axis, y_axis)) = settings.source.axes() else {
+        return IVec2::ZERO;
+    };
+
+    // Not sure why they are options
+    let (Some(analog_x), Some(analog_y)) = (pad.get(x_axis), pad.get(y_axis)) else {
+        return IVec2::ZERO;
+    };
+
+    settings.digitize(Vec2::new(analog_x, analog_y))
+}
+
 fn generate_offline_input_streams(
     mut stream: ResMut<InputStream>,
     keys: Res<ButtonInput<KeyCode>>,
     pad_query: Query<(Entity, &Gamepad)>,
+    stick_config: Res<StickConfig>,
 ) {
     let mut new_states = HashMap::<InputDevice, u16>::new();
 
@@ -403,17 +497,7 @@ fn generate_offline_input_streams(
             }
         }
 
-        // Analog stick (Not sure why they are options)
-        let analog_x = pad.get(GamepadAxis::LeftStickX).unwrap();
-        let analog_y = pad.get(GamepadAxis::LeftStickY).unwrap();
-
-        if analog_x.abs() > STICK_DEAD_ZONE {
-            stick.x += analog_x.signum() as i32;
-        };
-
-        if analog_y.abs() > STICK_DEAD_ZONE {
-            stick.y += analog_y.signum() as i32;
-        };
+        stick += read_analog_stick(pad, stick_config.get(InputDevice::Controller(entity)));
 
         // Clamps values from -1 to 1 (in cases where dpad and analog stick press the same way)
         stick.x = stick.x.signum();
@@ -2,8 +2,8 @@ use bevy::prelude::*;
 use bevy_ggrs::AddRollbackCommandExtension;
 use characters::{GaugeType, Gauges};
 use foundation::{
-    Area, CharacterFacing, Clock, MatchState, Owner, Pickup, PickupRequest, Player,
-    RollbackSchedule, SystemStep, MAX_COMBAT_DURATION,
+    Area, CharacterFacing, Clock, MatchRules, MatchState, Owner, Pickup, PickupRequest, Player,
+    RollbackSchedule, SystemStep, FPS,
 };
 
 use crate::{
@@ -58,6 +58,7 @@ pub fn spawn_pickups(
     mut commands: Commands,
     clock: Res<Clock>,
     models: Res<Models>,
+    rules: Res<MatchRules>,
     query: Query<(&Transform, &Player, &CharacterFacing)>,
 ) {
     let PickupRequest {
@@ -74,7 +75,7 @@ pub fn spawn_pickups(
     let (player_tf, player, facing) = query.get(trigger.target()).unwrap();
 
     // These will get despawned in the post-round cleanup
-    let despawn_after = lifetime.unwrap_or((60.0 * MAX_COMBAT_DURATION) as usize);
+    let despawn_after = lifetime.unwrap_or((FPS * rules.max_combat_duration()) as usize);
 
     let target = commands
         .spawn((
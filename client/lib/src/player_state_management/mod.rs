@@ -12,7 +12,7 @@ use foundation::{MatchState, RollbackSchedule, SystemStep};
 
 use bevy::prelude::*;
 
-pub use move_activation::MoveBuffer;
+pub use move_activation::{ActivationReport, MoveBuffer};
 pub use player_setup::reset_combat;
 
 pub struct PlayerStateManagementPlugin;
@@ -7,9 +7,6 @@ use player_state::PlayerState;
 
 use crate::event_spreading::StartAction;
 
-// In frames
-const INPUT_BUFFER: usize = 6;
-
 #[derive(Debug, Default, Component, Reflect, Clone)]
 pub struct MoveBuffer {
     buffer: HashMap<ActionId, usize>,
@@ -22,11 +19,11 @@ impl MoveBuffer {
         }
     }
 
-    fn clear_old(&mut self, current_frame: usize) {
-        self.buffer.retain(|_, frame| {
-            if current_frame <= *frame {
+    fn clear_old(&mut self, current_frame: usize, buffer_length: impl Fn(ActionId) -> usize) {
+        self.buffer.retain(|id, frame| {
+            if *frame <= current_frame {
                 // Default case, retain those who are fresh
-                current_frame - *frame < INPUT_BUFFER
+                current_frame - *frame < buffer_length(*id)
             } else {
                 // Round has restarted, clear the buffer
                 false
@@ -43,29 +40,61 @@ impl MoveBuffer {
         character: &Character,
         windows: &Vec<CancelType>,
         situation: Situation,
+        report: &mut ActivationReport,
     ) -> Vec<(usize, ActionId)> {
         self.buffer
             .iter()
             .filter_map(|(id, frame)| {
-                if let Some(action) = character.get_move(*id) {
-                    if action.requirement.check(*id, windows, &situation) {
-                        return Some((*frame, *id));
-                    }
+                let Some(action) = character.get_move(*id) else {
+                    report
+                        .rejected
+                        .push((*id, "Character doesn't have the move".into()));
+                    return None;
+                };
+
+                if let Some(failure) = action.requirement.first_failure(*id, windows, &situation) {
+                    report.rejected.push((*id, format!("{failure:?}")));
+                    return None;
                 }
-                None
+
+                Some((*frame, *id))
             })
             .collect()
     }
 }
 
+/// What happened in the move pipeline on the latest frame, for debugging
+#[derive(Debug, Default, Component, Clone)]
+pub struct ActivationReport {
+    pub frame: usize,
+    pub parsed: Vec<ActionId>,
+    pub buffered: Vec<ActionId>,
+    pub rejected: Vec<(ActionId, String)>,
+    pub activated: Option<ActionId>,
+    pub in_hitstop: bool,
+}
+
 pub(super) fn manage_buffer(
     clock: Res<Clock>,
-    mut query: Query<(&mut MoveBuffer, &mut InputParser)>,
+    mut query: Query<(
+        &mut MoveBuffer,
+        &mut InputParser,
+        &mut ActivationReport,
+        &Character,
+    )>,
 ) {
     // Read from the input parser and fill the buffer
-    for (mut buffer, mut parser) in &mut query {
-        buffer.clear_old(clock.frame);
-        buffer.add_events(parser.get_events(), clock.frame);
+    for (mut buffer, mut parser, mut report, character) in &mut query {
+        let events = parser.get_events();
+        *report = ActivationReport {
+            frame: clock.frame,
+            parsed: events.clone(),
+            ..default()
+        };
+
+        let timing = parser.timing();
+        buffer.clear_old(clock.frame, |id| character.input_buffer(id, timing));
+        buffer.add_events(events, clock.frame);
         parser.clear();
     }
 }
@@ -95,6 +124,7 @@ pub(super) fn move_activator(
         &CharacterFacing,
         &CharacterClock,
         &Combo,
+        &mut ActivationReport,
     )>,
 ) {
     // Activate and clear activating move
@@ -111,9 +141,13 @@ pub(super) fn move_activator(
         facing,
         char_clock,
         combo,
+        mut report,
     ) in &mut query
     {
+        report.buffered = buffer.buffer.keys().copied().collect();
+
         if char_clock.hitstop_frames > 0 {
+            report.in_hitstop = true;
             continue;
         }
 
@@ -134,7 +168,7 @@ pub(super) fn move_activator(
             );
 
             let situation_moves =
-                buffer.get_situation_moves(character, &state.cancels(), situation);
+                buffer.get_situation_moves(character, &state.cancels(), situation, &mut report);
 
             if situation_moves.is_empty() {
                 continue;
@@ -150,7 +184,10 @@ pub(super) fn move_activator(
                 .1
         };
 
-        let action = character.get_move(to_activate).unwrap();
+        let Some(action) = character.get_move(to_activate) else {
+            continue;
+        };
+        report.activated = Some(to_activate);
 
         buffer.buffer.retain(|id, _| *id != to_activate);
         if action.transient {
@@ -163,3 +200,45 @@ pub(super) fn move_activator(
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use foundation::InputTiming;
+
+    use super::*;
+
+    #[test]
+    fn buffered_inputs_expire() {
+        let timing = InputTiming::Standard;
+        let mut buffer = MoveBuffer::default();
+        buffer.add_events(vec![ActionId::ForwardDash], 10);
+
+        let last_frame = 10 + timing.move_buffer() - 1;
+        buffer.clear_old(last_frame, |_| timing.move_buffer());
+        assert!(buffer.buffer.contains_key(&ActionId::ForwardDash));
+
+        buffer.clear_old(last_frame + 1, |_| timing.move_buffer());
+        assert!(buffer.buffer.is_empty());
+    }
+
+    #[test]
+    fn actions_override_the_buffer() {
+        let timing = InputTiming::Strict;
+        let buffer_length = |id| {
+            if id == ActionId::BackDash {
+                20
+            } else {
+                timing.move_buffer()
+            }
+        };
+
+        let mut buffer = MoveBuffer::default();
+        buffer.add_events(vec![ActionId::ForwardDash, ActionId::BackDash], 0);
+        buffer.clear_old(timing.move_buffer(), buffer_length);
+        assert!(!buffer.buffer.contains_key(&ActionId::ForwardDash));
+        assert!(buffer.buffer.contains_key(&ActionId::BackDash));
+
+        buffer.clear_old(20, buffer_length);
+        assert!(buffer.buffer.is_empty());
+    }
+}
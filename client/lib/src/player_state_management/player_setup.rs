@@ -1,16 +1,18 @@
-use bevy::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
 use bevy_ggrs::AddRollbackCommandExtension;
 use characters::{Character, Gauges, Hurtboxes, Inventory};
 use foundation::{
-    AnimationType, CharacterClock, CharacterFacing, Characters, Clock, Combo, Facing, InMatch,
-    Player, Players, Stats, WagArgs,
+    AnimationType, CharacterClock, CharacterFacing, Characters, Clock, Combo, ControlScheme,
+    ControlSchemes, EconomyLedger, Facing, InMatch, InputTiming, InputTimings, MatchRules,
+    Palettes, Player, Players, Stats, Transaction, WagArgs,
 };
 use input_parsing::{InputParser, PadBundle};
 use player_state::PlayerState;
 
 use crate::{
     assets::{
-        AnimationHelper, AnimationHelperSetup, CharacterShake, Models, Music, PlayerModelHook,
+        AnimationHelper, AnimationHelperSetup, CharacterData, CharacterShake, Models, Music,
+        PlayerModelHook,
     },
     damage::HitboxSpawner,
     event_spreading,
@@ -19,27 +21,45 @@ use crate::{
 
 use super::{
     condition_management, force_state, move_activation, move_advancement, player_flash,
-    side_switcher, size_adjustment, MoveBuffer,
+    side_switcher, size_adjustment, ActivationReport, MoveBuffer,
 };
 
 const PLAYER_SPAWN_DISTANCE: f32 = 2.5; // Distance from x=0(middle)
 
+#[allow(clippy::too_many_arguments)]
 pub fn setup_players(
     mut commands: Commands,
     characters: Res<Characters>,
     models: Res<Models>,
+    data: CharacterData,
     args: Res<WagArgs>,
     mut music: ResMut<Music>,
     maybe_players: Option<Res<Players>>,
+    maybe_timings: Option<Res<InputTimings>>,
+    maybe_schemes: Option<Res<ControlSchemes>>,
+    maybe_palettes: Option<Res<Palettes>>,
+    rules: Res<MatchRules>,
 ) {
     if maybe_players.is_some() {
         return;
     }
 
+    // Online matches sync the timings, local ones are picked in controller assignment
+    let timings = maybe_timings
+        .map(|timings| *timings)
+        .unwrap_or(InputTimings::uniform(args.input_timing));
+    let schemes = maybe_schemes.map(|schemes| *schemes).unwrap_or_default();
+    let starting_money = rules.starting_money + args.extra_starting_money();
+
     info!("Spawning players");
 
-    let char1 = Character::from(characters.p1);
-    let char2 = Character::from(characters.p2);
+    let char1 = data.build(characters.p1);
+    let char2 = data.build(characters.p2);
+
+    let palettes = maybe_palettes
+        .map(|palettes| *palettes)
+        .unwrap_or_default()
+        .avoid_mirror(&characters, char2.palettes.len());
 
     music.push(char1.theme_song);
 
@@ -50,7 +70,10 @@ pub fn setup_players(
             -PLAYER_SPAWN_DISTANCE,
             Player::One,
             char1,
-            &args,
+            palettes.get(Player::One),
+            timings.get(Player::One),
+            schemes.get(Player::One),
+            starting_money,
         ),
         two: spawn_player(
             &mut commands,
@@ -58,11 +81,21 @@ pub fn setup_players(
             PLAYER_SPAWN_DISTANCE,
             Player::Two,
             char2,
-            &args,
+            palettes.get(Player::Two),
+            timings.get(Player::Two),
+            schemes.get(Player::Two),
+            starting_money,
         ),
     };
 
     commands.insert_resource(players);
+
+    // New match, new books
+    let mut ledger = EconomyLedger::default();
+    for player in [Player::One, Player::Two] {
+        ledger.record(player, 0, Transaction::StartingMoney, starting_money as i64);
+    }
+    commands.insert_resource(ledger);
 }
 
 #[derive(Bundle, Default)]
@@ -70,6 +103,7 @@ struct PlayerDefaults {
     character_clock: CharacterClock,
     character_shake: CharacterShake,
     move_buffer: MoveBuffer,
+    activation_report: ActivationReport,
     player_velocity: PlayerVelocity,
     spawner: HitboxSpawner,
     state: PlayerState,
@@ -78,15 +112,20 @@ struct PlayerDefaults {
     combo: Combo,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_player(
     commands: &mut Commands,
     models: &Models,
     offset: f32,
     player: Player,
     character: Character,
-    args: &WagArgs,
+    palette: usize,
+    timing: InputTiming,
+    scheme: ControlScheme,
+    starting_money: usize,
 ) -> Entity {
-    let colors = character.colors[&player].clone();
+    let colors: HashMap<String, Color> =
+        character.palette(palette).colors.iter().cloned().collect();
     let model = character.model;
 
     commands
@@ -94,7 +133,7 @@ fn spawn_player(
             Transform::from_translation(Vec3::new(offset, GROUND_PLANE_HEIGHT, 0.0)),
             Gauges::from_stats(&character.base_stats, character.special_properties.clone()),
             PlayerDefaults::default(),
-            PadBundle::new(character.get_inputs()),
+            PadBundle::new(character.get_inputs(scheme), timing),
             Name::new(format!("Player {player}")),
             AnimationHelperSetup(character.generic_animations[&AnimationType::Default]),
             CharacterFacing::from(Facing::from_flipped(offset.is_sign_positive())),
@@ -103,10 +142,9 @@ fn spawn_player(
             character,
             player,
             StateScoped(InMatch),
-            {
-                let mut inv = Inventory::default();
-                inv.money += args.extra_starting_money();
-                inv
+            Inventory {
+                money: starting_money,
+                ..default()
             },
         ))
         .with_children(move |parent| {
@@ -138,6 +176,7 @@ fn spawn_player(
         .observe(crate::assets::shake_character)
         .observe(crate::camera::tilt_camera)
         .observe(crate::damage::snap_and_switch)
+        .observe(crate::damage::throw_tech)
         .observe(crate::damage::hitstun_events)
         .observe(crate::damage::blockstun_events)
         .observe(crate::damage::launch_events)
@@ -146,6 +185,11 @@ fn spawn_player(
         .observe(crate::movement::add_movement)
         .observe(crate::movement::handle_teleports)
         .observe(crate::pickup_management::spawn_pickups)
+        .observe(crate::puppet_management::spawn_puppets)
+        .observe(crate::puppet_management::command_puppets)
+        .observe(crate::puppet_management::despawn_puppets)
+        .observe(crate::item_management::fire_item_triggers)
+        .observe(crate::item_management::consume_items)
         .observe(crate::resources::modify_properties)
         .observe(crate::resources::clear_properties)
         .id()
@@ -1,8 +1,18 @@
 use bevy::prelude::*;
+use characters::ItemTrigger;
 use foundation::{CharacterClock, Combo, Player, Players, FPS};
 use player_state::PlayerState;
 
+fn end_combo(commands: &mut Commands, combos: &mut Query<&mut Combo>, attacker: Entity) {
+    let mut combo = combos.get_mut(attacker).unwrap();
+    if combo.ongoing() {
+        commands.trigger_targets(ItemTrigger::ComboEnd, attacker);
+    }
+    combo.reset();
+}
+
 pub fn stun_recovery(
+    mut commands: Commands,
     mut query: Query<(&mut PlayerState, &Player, &CharacterClock)>,
     mut combos: Query<&mut Combo>,
     players: Res<Players>,
@@ -11,7 +21,7 @@ pub fn stun_recovery(
         if let Some(unstun_frame) = state.unstun_frame() {
             if unstun_frame <= clock.frame {
                 state.recover(clock.frame);
-                combos.get_mut(players.get(player.other())).unwrap().reset();
+                end_combo(&mut commands, &mut combos, players.get(player.other()));
             }
         }
     }
@@ -20,6 +30,7 @@ pub fn stun_recovery(
 const QUICK_RISE_DURATION: usize = (FPS * 0.5) as usize;
 
 pub fn ground_recovery(
+    mut commands: Commands,
     mut combos: Query<&mut Combo>,
     players: Res<Players>,
     mut query: Query<(&mut PlayerState, &Player, &CharacterClock)>,
@@ -28,7 +39,7 @@ pub fn ground_recovery(
         if let Some(landing_frame) = state.otg_since() {
             if landing_frame + QUICK_RISE_DURATION <= clock.frame {
                 state.recover(clock.frame);
-                combos.get_mut(players.get(player.other())).unwrap().reset();
+                end_combo(&mut commands, &mut combos, players.get(player.other()));
             }
         }
     }
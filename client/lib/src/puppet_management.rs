@@ -0,0 +1,210 @@
+use bevy::prelude::*;
+use bevy_ggrs::AddRollbackCommandExtension;
+use characters::{ActionEvent, Character, Gauges, Hitbox, Inventory};
+use foundation::{
+    Area, CharacterClock, CharacterFacing, Clock, MatchState, Owner, Player, Players,
+    PuppetRequest, RollbackSchedule, Stats, SystemStep, VfxRequest, VisualEffect,
+};
+use input_parsing::InputParser;
+use player_state::{PlayerState, PuppetState};
+
+use crate::{
+    assets::Models,
+    damage::{HitTracker, HitboxSpawner},
+    entity_management::DespawnMarker,
+    event_spreading::{CommandPuppets, DespawnPuppets, SpawnVfx},
+    ui::Notifications,
+};
+
+pub struct PuppetPlugin;
+
+impl Plugin for PuppetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            RollbackSchedule,
+            advance_puppets.in_set(SystemStep::PlayerUpdates),
+        );
+    }
+}
+
+pub fn spawn_puppets(
+    trigger: Trigger<PuppetRequest>,
+    mut commands: Commands,
+    models: Res<Models>,
+    query: Query<(&Transform, &Player, &CharacterFacing)>,
+) {
+    let PuppetRequest {
+        spawn_point,
+        hurtbox,
+        health,
+        model,
+    } = *trigger.event();
+
+    let (player_tf, player, facing) = query.get(trigger.target()).unwrap();
+
+    let mut builder = commands.spawn((
+        Transform::from_translation(
+            facing.absolute.mirror_vec2(spawn_point).extend(0.0) + player_tf.translation,
+        ),
+        Visibility::default(),
+        PuppetState::new(health),
+        hurtbox,
+        Owner(*player),
+        StateScoped(MatchState::Combat),
+        Name::new(format!("Puppet of {player}")),
+    ));
+
+    if let Some(model) = model {
+        builder.with_children(|parent| {
+            parent.spawn(SceneRoot(models[&model].clone()));
+        });
+    }
+
+    builder.add_rollback();
+}
+
+pub fn command_puppets(
+    trigger: Trigger<CommandPuppets>,
+    players: Query<(&Player, &CharacterClock)>,
+    mut puppets: Query<(&Owner, &mut PuppetState)>,
+) {
+    let (player, clock) = players.get(trigger.target()).unwrap();
+    let CommandPuppets(action_id) = *trigger.event();
+
+    for (owner, mut puppet) in &mut puppets {
+        if **owner == *player {
+            puppet.start_action(action_id, clock.frame);
+        }
+    }
+}
+
+pub fn despawn_puppets(
+    trigger: Trigger<DespawnPuppets>,
+    mut commands: Commands,
+    players: Query<&Player>,
+    puppets: Query<(Entity, &Owner), With<PuppetState>>,
+) {
+    let player = players.get(trigger.target()).unwrap();
+
+    for (entity, owner) in &puppets {
+        if **owner == *player {
+            commands.entity(entity).insert(DespawnMarker(0));
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn advance_puppets(
+    mut commands: Commands,
+    abs_clock: Res<Clock>,
+    models: Res<Models>,
+    players: Res<Players>,
+    owners: Query<(
+        &PlayerState,
+        &Character,
+        &Inventory,
+        &Gauges,
+        &InputParser,
+        &Stats,
+        &CharacterClock,
+        &CharacterFacing,
+    )>,
+    mut puppets: Query<(Entity, &Owner, &Transform, &mut PuppetState)>,
+) {
+    for (entity, owner, tf, mut puppet) in &mut puppets {
+        let (state, character, inventory, gauges, parser, stats, clock, facing) =
+            owners.get(players.get(**owner)).unwrap();
+
+        if clock.hitstop_frames > 0 {
+            continue;
+        }
+
+        let situation = state.build_situation(
+            inventory.to_owned(),
+            gauges.to_owned(),
+            parser.to_owned(),
+            stats.to_owned(),
+            clock.frame,
+            abs_clock.frame,
+            tf.translation,
+            *facing,
+            Default::default(),
+        );
+
+        // Puppets only do a subset of what players can
+        for event in puppet.proceed_action(character, situation) {
+            match event {
+                ActionEvent::SpawnHitbox(attack) => {
+                    puppet.register_attack();
+                    HitboxSpawner::default().spawn_attack(
+                        &mut commands,
+                        &models,
+                        attack,
+                        abs_clock.frame,
+                        entity,
+                        facing,
+                        **owner,
+                        tf.translation,
+                    );
+                }
+                ActionEvent::Sound(sound) => commands.trigger(sound),
+                ActionEvent::AbsoluteVisualEffect(vfx) => commands.trigger(SpawnVfx(vfx, None)),
+                ActionEvent::RelativeVisualEffect(mut vfx) => {
+                    vfx.tf.translation += tf.translation;
+                    if facing.visual.to_flipped() {
+                        vfx.mirror = !vfx.mirror;
+                    }
+                    commands.trigger(SpawnVfx(vfx, Some(entity)));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// Runs before the regular hit detection so puppets can body block
+pub fn hit_puppets(
+    mut commands: Commands,
+    clock: Res<Clock>,
+    mut notifications: ResMut<Notifications>,
+    mut hitboxes: Query<(&Owner, &Transform, &Hitbox, &mut HitTracker)>,
+    mut puppets: Query<(Entity, &Owner, &Transform, &Area, &mut PuppetState)>,
+) {
+    for (hit_owner, hitbox_tf, hitbox, mut tracker) in &mut hitboxes {
+        if !tracker.active(clock.frame) || tracker.hits == 0 {
+            continue;
+        }
+
+        let offset_hitbox = hitbox.with_offset(hitbox_tf.translation.truncate());
+
+        for (entity, owner, tf, hurtbox, mut puppet) in &mut puppets {
+            if **owner == **hit_owner || puppet.health == 0 {
+                continue;
+            }
+
+            let Some(overlap) = hurtbox
+                .with_offset(tf.translation.truncate())
+                .intersection(&offset_hitbox)
+            else {
+                continue;
+            };
+
+            tracker.register_hit(clock.frame);
+            commands.trigger(SpawnVfx(
+                VfxRequest {
+                    effect: VisualEffect::Hit,
+                    tf: Transform::from_translation(overlap.center().extend(0.0)),
+                    ..default()
+                },
+                None,
+            ));
+
+            if puppet.take_hit() {
+                commands.entity(entity).insert(DespawnMarker(0));
+                notifications.add(**owner, "Puppet down".to_owned());
+            }
+
+            break;
+        }
+    }
+}
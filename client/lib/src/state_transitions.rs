@@ -1,11 +1,13 @@
+use std::{io::Write, path::Path};
+
 use bevy::{asset::LoadState, prelude::*, state::state::FreelyMutableState};
 
-use characters::{Character, GaugeType, Gauges, Inventory};
+use characters::{Character, GaugeType, Gauges, Inventory, ItemTrigger};
 use foundation::{
-    Clock, GameResult, GameState, InCharacterSelect, InMatch, MatchState, Player, RollbackSchedule,
-    RoundLog, RoundResult, Sound, SoundRequest, SystemStep, VoiceLine, BASE_ROUND_MONEY, FPS,
-    MAX_COMBAT_DURATION, POST_ROUND_DURATION, PRE_ROUND_DURATION, ROUNDS_TO_WIN,
-    ROUND_MONEY_BUILDUP, VICTORY_BONUS,
+    Clock, EconomyLedger, GameResult, GameState, InCharacterSelect, InMatch, MatchRules,
+    MatchState, Player, RollbackSchedule, RoundLog, RoundPerformance, RoundResult, RoundStats,
+    Sound, SoundRequest, SystemStep, Transaction, VoiceLine, WagArgs, FPS, POST_ROUND_DURATION,
+    PRE_ROUND_DURATION,
 };
 use input_parsing::InputParser;
 
@@ -27,13 +29,17 @@ impl Plugin for StateTransitionPlugin {
                 (
                     end_loading.run_if(in_state(MatchState::Loading)),
                     end_combat.run_if(in_state(MatchState::Combat)),
+                    (start_round_items, clear_round_stats)
+                        .run_if(in_state(MatchState::Combat).and(state_changed::<MatchState>)),
                     clear_between_states.run_if(state_changed::<GameState>),
                     transition_after_timer::<GameState>,
                     transition_after_timer::<MatchState>,
                 )
                     .chain()
                     .in_set(SystemStep::StateTransitions),
-            );
+            )
+            .add_systems(OnEnter(MatchState::EndScreen), export_economy_ledger)
+            .init_resource::<EconomyLedger>();
     }
 }
 
@@ -54,12 +60,15 @@ pub fn end_combat(
     mut next_match_state: ResMut<NextState<MatchState>>,
     mut music: ResMut<Music>,
     mut animation_players: Query<&mut AnimationPlayer>,
+    rules: Res<MatchRules>,
+    mut ledger: ResMut<EconomyLedger>,
+    round_stats: Res<RoundStats>,
 ) {
     let player_dead = players
         .iter()
         .any(|(properties, _, _, _)| properties.get(GaugeType::Health).unwrap().is_empty());
 
-    let time_out = clock.relative_frame() as f32 / FPS >= MAX_COMBAT_DURATION;
+    let time_out = clock.relative_frame() as f32 / FPS >= rules.max_combat_duration();
     let round_over = player_dead || time_out;
 
     if !round_over {
@@ -74,16 +83,27 @@ pub fn end_combat(
     });
 
     debug_assert!(ordered_healths.len() == 2);
-    let [(winner_props, winner, winner_inventory, winner_character), (loser_props, _, loser_inventory, loser_character)] =
+    let [(winner_props, winner, winner_inventory, winner_character), (loser_props, loser, loser_inventory, loser_character)] =
         &mut ordered_healths[..]
     else {
         panic!("Couldn't unpack players");
     };
 
-    let round_money = BASE_ROUND_MONEY + ROUND_MONEY_BUILDUP * round_log.rounds_played();
+    // Interest is paid on what was left before this round's payouts
+    let winner_unspent = winner_inventory.money;
+    let loser_unspent = loser_inventory.money;
+
+    let rounds_played = round_log.rounds_played();
+    let round_money = rules.round_money(rounds_played);
 
     for player in [Player::One, Player::Two] {
         notifications.add(player, format!("Round payout: ${round_money}"));
+        ledger.record(
+            player,
+            rounds_played,
+            Transaction::RoundPayout,
+            round_money as i64,
+        );
 
         let meter_money = if player == **winner {
             let meter_money = winner_props.get(GaugeType::Meter).unwrap().current;
@@ -96,6 +116,12 @@ pub fn end_combat(
         };
 
         notifications.add(player, format!("Meter payout: ${meter_money}"));
+        ledger.record(
+            player,
+            rounds_played,
+            Transaction::MeterPayout,
+            meter_money as i64,
+        );
     }
 
     winner_inventory.remove_one_round_consumables(winner_character);
@@ -114,8 +140,14 @@ pub fn end_combat(
         announcer.tie();
         RoundResult { winner: None }
     } else {
-        notifications.add(**winner, format!("Victory bonus: ${VICTORY_BONUS}"));
-        winner_inventory.money += VICTORY_BONUS;
+        notifications.add(**winner, format!("Victory bonus: ${}", rules.victory_bonus));
+        winner_inventory.money += rules.victory_bonus;
+        ledger.record(
+            **winner,
+            rounds_played,
+            Transaction::VictoryBonus,
+            rules.victory_bonus as i64,
+        );
 
         commands.trigger(SoundRequest::from(
             loser_character.get_voiceline(VoiceLine::Defeat),
@@ -129,7 +161,28 @@ pub fn end_combat(
 
     round_log.add(result);
 
-    let game_over = round_log.wins(**winner) >= ROUNDS_TO_WIN;
+    let perfect_round =
+        result.winner.is_some() && winner_props.get(GaugeType::Health).unwrap().is_full();
+    for (player, inventory, unspent, perfect_round) in [
+        (**winner, winner_inventory, winner_unspent, perfect_round),
+        (**loser, loser_inventory, loser_unspent, false),
+    ] {
+        let performance = RoundPerformance {
+            unspent,
+            loss_streak: round_log.loss_streak(player),
+            perfect_round,
+            first_hit: round_stats.first_hit == Some(player),
+            throw_techs: round_stats.throw_techs(player),
+        };
+
+        for (transaction, amount) in rules.income.bonuses(performance) {
+            inventory.money += amount;
+            notifications.add(player, format!("{}: ${amount}", transaction.name()));
+            ledger.record(player, rounds_played, transaction, amount as i64);
+        }
+    }
+
+    let game_over = round_log.wins(**winner) >= rules.rounds_to_win;
 
     let next_state = if game_over {
         commands.insert_resource(GameResult { winner: **winner });
@@ -154,6 +207,39 @@ pub fn end_combat(
     });
 }
 
+fn export_economy_ledger(args: Res<WagArgs>, ledger: Res<EconomyLedger>) {
+    let Some(path) = &args.economy_log else {
+        return;
+    };
+
+    if let Err(err) = append_json_line(path, &ledger) {
+        warn!(
+            "Couldn't write the economy log to {}: {err}",
+            path.display()
+        );
+    }
+}
+
+/// One match per line, so many matches can go in the same file
+fn append_json_line(path: &Path, ledger: &EconomyLedger) -> std::io::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    writeln!(file, "{}", serde_json::to_string(ledger)?)
+}
+
+fn clear_round_stats(mut round_stats: ResMut<RoundStats>) {
+    round_stats.clear();
+}
+
+fn start_round_items(mut commands: Commands, players: Query<Entity, With<Player>>) {
+    for entity in &players {
+        commands.trigger_targets(ItemTrigger::RoundStart, entity);
+    }
+}
+
 fn transition_after_timer<T: FreelyMutableState>(
     mut commands: Commands,
     timer_resource: Option<Res<TransitionTimer<T>>>,
@@ -209,3 +295,31 @@ fn clear_between_states(mut players: Query<&mut InputParser>) {
         parser.clear();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use foundation::ItemId;
+
+    use super::*;
+
+    #[test]
+    fn economy_log_has_a_line_per_match() {
+        let path = std::env::temp_dir().join("wag_economy_log_test.jsonl");
+        let _ = std::fs::remove_file(&path);
+
+        let mut ledger = EconomyLedger::default();
+        ledger.record(Player::Two, 3, Transaction::Refund(ItemId::Boots), 100);
+        append_json_line(&path, &ledger).unwrap();
+        append_json_line(&path, &ledger).unwrap();
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let lines: Vec<&str> = log.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            serde_json::from_str::<EconomyLedger>(lines[0]).unwrap(),
+            ledger
+        );
+    }
+}
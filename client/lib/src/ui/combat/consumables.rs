@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use characters::{Character, ConsumableType, Inventory, ItemCategory};
+use foundation::{Player, RESOURCE_COUNTER_TEXT_COLOR};
+
+#[derive(Debug, Component, Deref)]
+pub struct ConsumableCharges(pub Player);
+
+pub fn setup_charges(commands: &mut Commands, parent: Entity, font: Handle<Font>, player: Player) {
+    commands.spawn((
+        Text::default(),
+        TextFont {
+            font,
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(RESOURCE_COUNTER_TEXT_COLOR),
+        TextLayout::new_with_justify(match player {
+            Player::One => JustifyText::Left,
+            Player::Two => JustifyText::Right,
+        }),
+        ConsumableCharges(player),
+        Name::new("Consumable charges"),
+        ChildOf(parent),
+    ));
+}
+
+pub fn update_charges(
+    mut texts: Query<(&mut Text, &ConsumableCharges)>,
+    players: Query<(&Player, &Character, &Inventory)>,
+) {
+    for (player, character, inventory) in &players {
+        let mut charges: Vec<_> = inventory
+            .items
+            .iter()
+            .filter_map(|(id, count)| Some((character.items.get(id)?, *count)))
+            .filter(|(item, _)| {
+                matches!(
+                    item.category,
+                    ItemCategory::Consumable(ConsumableType::UntilUsed)
+                )
+            })
+            .map(|(item, count)| (item.name.clone(), count))
+            .collect();
+        // Hash map order would make the lines jump around
+        charges.sort();
+
+        for (mut text, owner) in &mut texts {
+            if **owner != *player {
+                continue;
+            }
+
+            text.0 = charges
+                .iter()
+                .map(|(name, count)| format!("{name} x{count}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+    }
+}
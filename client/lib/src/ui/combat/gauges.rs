@@ -72,6 +72,8 @@ pub fn setup_counter(
     marker: impl Component,
     name: impl Into<std::borrow::Cow<'static, str>>,
 ) {
+    let label = instructions.label.clone();
+
     commands
         .spawn((
             Node {
@@ -104,11 +106,7 @@ pub fn setup_counter(
             );
 
             let spawn_label = |root: &mut ChildSpawnerCommands| {
-                root.spawn((
-                    Text::new(instructions.label),
-                    text_style.clone(),
-                    Name::new("Label"),
-                ));
+                root.spawn((Text::new(label), text_style.clone(), Name::new("Label")));
             };
 
             let spawn_counter = |root: &mut ChildSpawnerCommands| {
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+mod consumables;
+pub use consumables::update_charges;
+
 mod gauges;
 pub use gauges::{update_bars, update_counters, ResourceCounter, ResourceGauge};
 
@@ -10,7 +13,7 @@ mod round_timer;
 pub use round_timer::update_timer;
 
 use characters::{GaugeType, Gauges, RenderInstructions, ResourceBarVisual};
-use foundation::{InMatch, MatchState, Player, Players, RoundLog, GENERIC_TEXT_COLOR};
+use foundation::{InMatch, MatchRules, MatchState, Player, Players, RoundLog, GENERIC_TEXT_COLOR};
 
 use crate::{assets::Fonts, entity_management::VisibleInStates};
 
@@ -26,6 +29,7 @@ pub fn setup_combat_hud(
     properties: Query<&Gauges>,
     players: Res<Players>,
     existing_huds: Query<Entity, With<CombatUI>>,
+    rules: Res<MatchRules>,
 ) {
     for entity in &existing_huds {
         commands.entity(entity).despawn();
@@ -61,7 +65,13 @@ pub fn setup_combat_hud(
         Player::One,
         properties.get(players.one).unwrap(),
     );
-    round_timer::setup_timer(&mut commands, container, fonts.basic.clone(), timer_width);
+    round_timer::setup_timer(
+        &mut commands,
+        container,
+        fonts.basic.clone(),
+        timer_width,
+        rules.combat_duration,
+    );
     setup_player_hud(
         &mut commands,
         container,
@@ -182,13 +192,13 @@ fn setup_bottom_hud(
             continue;
         }
 
-        match property.render_instructions {
+        match &property.render_instructions {
             RenderInstructions::Bar(bar) => {
                 gauges::setup_bar(
                     commands,
                     player,
                     container,
-                    bar,
+                    *bar,
                     ResourceGauge(player, *prop_type),
                     format!("Special resource bar {prop_type:?}"),
                 );
@@ -199,7 +209,7 @@ fn setup_bottom_hud(
                     player,
                     container,
                     fonts.basic.clone(),
-                    counter,
+                    counter.clone(),
                     ResourceCounter(player, *prop_type),
                     format!("Special resource counter {prop_type:?}"),
                 );
@@ -208,6 +218,8 @@ fn setup_bottom_hud(
         }
     }
 
+    consumables::setup_charges(commands, container, fonts.basic.clone(), player);
+
     gauges::setup_bar(
         commands,
         player,
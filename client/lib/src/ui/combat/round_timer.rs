@@ -1,14 +1,18 @@
 use bevy::prelude::*;
 
-use foundation::{Clock, COMBAT_DURATION, FPS, MAX_COMBAT_DURATION, ROUND_TIMER_TEXT_COLOR};
+use foundation::{Clock, MatchRules, FPS, ROUND_TIMER_TEXT_COLOR};
 
 #[derive(Debug, Component)]
 pub struct RoundTimer;
 
-pub fn update_timer(mut query: Query<&mut Text, With<RoundTimer>>, clock: Res<Clock>) {
+pub fn update_timer(
+    mut query: Query<&mut Text, With<RoundTimer>>,
+    clock: Res<Clock>,
+    rules: Res<MatchRules>,
+) {
     let elapsed_secs = clock.relative_frame() as f32 / FPS;
-    let secs_left = (MAX_COMBAT_DURATION - elapsed_secs)
-        .clamp(0.0, COMBAT_DURATION - 1.0)
+    let secs_left = (rules.max_combat_duration() - elapsed_secs)
+        .clamp(0.0, rules.combat_duration - 1.0)
         .ceil() as usize;
 
     for mut txt in &mut query {
@@ -21,6 +25,7 @@ pub fn setup_timer(
     parent: Entity,
     font: Handle<Font>,
     width_percentage: f32,
+    combat_duration: f32,
 ) {
     let container = commands
         .spawn((
@@ -38,7 +43,7 @@ pub fn setup_timer(
         .id();
 
     commands.spawn((
-        Text::new(COMBAT_DURATION.round().to_string()),
+        Text::new(combat_duration.round().to_string()),
         TextFont {
             font,
             font_size: 100.0,
@@ -27,6 +27,7 @@ impl Plugin for UIPlugin {
                     (
                         combat::update_bars,
                         combat::update_counters,
+                        combat::update_charges,
                         combat::update_timer,
                         combat::update_score,
                     )
@@ -46,6 +47,7 @@ impl Plugin for UIPlugin {
             .add_systems(
                 RollbackSchedule,
                 (
+                    shop::restock_shops.run_if(state_changed::<MatchState>),
                     shop::navigate_shop,
                     shop::update_slot_visuals,
                     shop::update_top_bar_moneys,
@@ -1,8 +1,10 @@
 mod setup_shop;
 mod shop_inputs;
 mod shop_rendering;
+mod shop_stock;
 mod shops_resource;
 
+pub(super) use setup_shop::restock_shops;
 pub use setup_shop::setup_shop;
 pub(super) use shop_inputs::navigate_shop;
 pub(super) use shop_rendering::{
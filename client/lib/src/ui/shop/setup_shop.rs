@@ -1,16 +1,18 @@
 use bevy::prelude::*;
 
-use characters::{Character, Item};
+use characters::{Character, Inventory, Item};
 use foundation::{
-    Icon, Icons, InMatch, ItemId, MatchState, Owner, Player, Players, GENERIC_TEXT_COLOR,
-    ITEM_SLOT_COMPONENT_COLOR, ITEM_SLOT_DEFAULT_COLOR, ITEM_SLOT_DISABLED_COLOR,
-    ITEM_SLOT_HIGHLIGHT_COLOR, ITEM_SLOT_OWNED_COLOR, ITEM_SLOT_UPGRADE_COLOR,
-    SHOP_DARK_BACKGROUND_COLOR, SHOP_DIVIDER_COLOR, SHOP_TIMER_BACKGROUND_COLOR,
+    Icon, Icons, InMatch, ItemId, MatchRules, MatchState, Owner, Player, Players, RoundLog,
+    ShopMode, GENERIC_TEXT_COLOR, ITEM_SLOT_COMPONENT_COLOR, ITEM_SLOT_DEFAULT_COLOR,
+    ITEM_SLOT_DISABLED_COLOR, ITEM_SLOT_HIGHLIGHT_COLOR, ITEM_SLOT_OWNED_COLOR,
+    ITEM_SLOT_UPGRADE_COLOR, SHOP_DARK_BACKGROUND_COLOR, SHOP_DIVIDER_COLOR,
+    SHOP_TIMER_BACKGROUND_COLOR,
 };
 
 use crate::assets::Fonts;
 use crate::entity_management::VisibleInStates;
 
+use super::shop_stock::shop_stock;
 use super::shops_resource::{Shop, ShopComponents, ShopComponentsBuilder, Shops};
 use super::SHOP_COLUMNS;
 
@@ -19,10 +21,9 @@ pub struct ShopItem(pub ItemId);
 
 pub fn setup_shop(
     mut commands: Commands,
-    characters: Query<&Character>,
-    players: Res<Players>,
     fonts: Res<Fonts>,
     icons: Res<Icons>,
+    rules: Res<MatchRules>,
 ) {
     let root = commands
         .spawn((
@@ -58,42 +59,84 @@ pub fn setup_shop(
         ))
         .id();
 
-    let player_one_components = setup_shop_root(
-        &mut commands,
-        container,
-        Player::One,
-        characters.get(players.one).unwrap(),
-        &icons,
-        &fonts,
-    );
+    let player_one_components =
+        setup_shop_root(&mut commands, container, Player::One, &icons, &fonts);
 
-    let player_two_components = setup_shop_root(
-        &mut commands,
-        container,
-        Player::Two,
-        characters.get(players.two).unwrap(),
-        &icons,
-        &fonts,
-    );
+    let player_two_components =
+        setup_shop_root(&mut commands, container, Player::Two, &icons, &fonts);
 
-    setup_bottom_bars(&mut commands, root);
+    setup_bottom_bars(&mut commands, root, &rules);
 
+    // Grids get filled when the shop opens
     commands.insert_resource(Shops {
         player_one: Shop {
             components: player_one_components,
             selected_index: 0,
-            max_index: characters.get(players.one).unwrap().items.len() - 1,
+            max_index: 0,
             closed: false,
+            rerolls: 0,
+            purchases: vec![],
+            editing_build_order: false,
         },
         player_two: Shop {
             components: player_two_components,
             selected_index: 0,
-            max_index: characters.get(players.two).unwrap().items.len() - 1,
+            max_index: 0,
             closed: false,
+            rerolls: 0,
+            purchases: vec![],
+            editing_build_order: false,
         },
     });
 }
 
+pub fn restock_shops(
+    mut commands: Commands,
+    mut shops: ResMut<Shops>,
+    query: Query<(&Character, &Inventory)>,
+    players: Res<Players>,
+    icons: Res<Icons>,
+    rules: Res<MatchRules>,
+    round_log: Res<RoundLog>,
+) {
+    for player in [Player::One, Player::Two] {
+        let (character, inventory) = query.get(players.get(player)).unwrap();
+        let (_, opponent) = query.get(players.get(player.other())).unwrap();
+
+        let shop = shops.get_mut_shop(&player);
+        shop.rerolls = 0;
+        shop.purchases.clear();
+        shop.editing_build_order = false;
+
+        let seed = rules.shop_seed(player, round_log.rounds_played(), 0);
+        restock(
+            &mut commands,
+            &icons,
+            shop,
+            player,
+            character,
+            shop_stock(character, inventory, opponent, &rules, seed),
+        );
+    }
+}
+
+/// Replaces the items in the grid
+pub fn restock(
+    commands: &mut Commands,
+    icons: &Icons,
+    shop: &mut Shop,
+    player: Player,
+    character: &Character,
+    stock: Vec<ItemId>,
+) {
+    let grid = shop.components.grid;
+    commands.entity(grid).despawn_related::<Children>();
+
+    shop.components.grid_items = fill_item_grid(commands, icons, grid, player, character, stock);
+    shop.max_index = shop.components.grid_items.len().saturating_sub(1);
+    shop.selected_index = 0;
+}
+
 #[derive(Debug, Component)]
 pub struct ShopMoney;
 #[derive(Debug, Component)]
@@ -136,7 +179,7 @@ fn setup_shop_top_bar(commands: &mut Commands, container: Entity, fonts: &Fonts)
         });
 }
 
-fn setup_bottom_bars(commands: &mut Commands, container: Entity) {
+fn setup_bottom_bars(commands: &mut Commands, container: Entity, rules: &MatchRules) {
     shop_ribbon(
         commands,
         container,
@@ -150,23 +193,29 @@ fn setup_bottom_bars(commands: &mut Commands, container: Entity) {
             ("Not purchasable", ITEM_SLOT_DISABLED_COLOR),
         ],
     );
-    shop_ribbon(
-        commands,
-        container,
-        "Shop button guide",
-        &[
-            ("A/Cross to buy", GENERIC_TEXT_COLOR),
-            ("B/Circle to sell", GENERIC_TEXT_COLOR),
-            ("X/Square to proceed", GENERIC_TEXT_COLOR),
-        ],
-    );
+
+    let mut buttons = vec![
+        ("A/Cross to buy".to_owned(), GENERIC_TEXT_COLOR),
+        ("B/Circle to sell".to_owned(), GENERIC_TEXT_COLOR),
+        ("X/Square to proceed".to_owned(), GENERIC_TEXT_COLOR),
+        ("L1 to undo".to_owned(), GENERIC_TEXT_COLOR),
+        ("R1 to quick buy".to_owned(), GENERIC_TEXT_COLOR),
+        ("Select for build order".to_owned(), GENERIC_TEXT_COLOR),
+    ];
+    if let (ShopMode::Random(_), Some(cost)) = (rules.shop_mode, rules.reroll_cost) {
+        buttons.push((
+            format!("Y/Triangle to reroll (${cost})"),
+            GENERIC_TEXT_COLOR,
+        ));
+    }
+    shop_ribbon(commands, container, "Shop button guide", &buttons);
 }
 
 fn shop_ribbon(
     commands: &mut Commands,
     container: Entity,
     title: &'static str,
-    items: &[(&'static str, Color)],
+    items: &[(impl Into<String> + Clone, Color)],
 ) {
     let style = TextFont {
         font_size: 30.0,
@@ -187,7 +236,7 @@ fn shop_ribbon(
         ))
         .with_children(|cb| {
             for (text, color) in items {
-                cb.spawn((Text::new(*text), style.clone(), TextColor(*color)));
+                cb.spawn((Text::new(text.clone()), style.clone(), TextColor(*color)));
             }
         });
 }
@@ -196,7 +245,6 @@ fn setup_shop_root(
     commands: &mut Commands,
     parent: Entity,
     owner: Player,
-    character: &Character,
     icons: &Icons,
     fonts: &Fonts,
 ) -> ShopComponents {
@@ -220,14 +268,7 @@ fn setup_shop_root(
         .id();
 
     setup_info_panel(commands, container, &mut shop_root_builder, fonts, icons);
-    setup_shop_grid(
-        commands,
-        icons,
-        container,
-        &mut shop_root_builder,
-        character,
-        owner,
-    );
+    setup_shop_grid(commands, container, &mut shop_root_builder);
     setup_countdown_number(commands, container, &mut shop_root_builder, fonts);
 
     shop_root_builder.build()
@@ -377,9 +418,17 @@ fn setup_explanation_box(
         commands,
         container,
         vec!["Depends on: ", " "],
-        basic_style,
+        basic_style.clone(),
         "Dependencies",
     ));
+
+    shop_root.stats = Some(setup_text_sections(
+        commands,
+        container,
+        vec!["Stats: ", " "],
+        basic_style,
+        "Stat changes",
+    ));
 }
 
 fn setup_text_sections(
@@ -403,29 +452,22 @@ fn setup_text_sections(
         .id()
 }
 
-fn setup_shop_grid(
-    commands: &mut Commands,
-    icons: &Icons,
-    parent: Entity,
-    shop_root: &mut ShopComponentsBuilder,
-    character: &Character,
-    player: Player,
-) {
-    let container = commands
-        .spawn((
-            Node {
-                display: Display::Grid,
-                grid_template_columns: RepeatedGridTrack::flex(SHOP_COLUMNS as u16, 1.0),
-                row_gap: Val::Px(5.0),
-                column_gap: Val::Px(5.0),
-                ..default()
-            },
-            Name::new("Available items root"),
-            ChildOf(parent),
-        ))
-        .id();
-
-    shop_root.grid_items = fill_item_grid(commands, icons, container, player, character);
+fn setup_shop_grid(commands: &mut Commands, parent: Entity, shop_root: &mut ShopComponentsBuilder) {
+    shop_root.grid = Some(
+        commands
+            .spawn((
+                Node {
+                    display: Display::Grid,
+                    grid_template_columns: RepeatedGridTrack::flex(SHOP_COLUMNS as u16, 1.0),
+                    row_gap: Val::Px(5.0),
+                    column_gap: Val::Px(5.0),
+                    ..default()
+                },
+                Name::new("Available items root"),
+                ChildOf(parent),
+            ))
+            .id(),
+    );
 }
 
 fn fill_item_grid(
@@ -434,14 +476,22 @@ fn fill_item_grid(
     parent: Entity,
     player: Player,
     character: &Character,
+    mut stock: Vec<ItemId>,
 ) -> Vec<Entity> {
-    let mut pairs = character.items.iter().collect::<Vec<_>>();
-
-    pairs.sort_by_key(|(id, _)| (recursive_cost(character, **id), **id));
+    stock.sort_by_key(|id| (recursive_cost(character, *id), *id));
 
-    pairs
+    stock
         .into_iter()
-        .map(|(id, item)| setup_shop_item(commands, icons, parent, player, *id, item.clone()))
+        .map(|id| {
+            setup_shop_item(
+                commands,
+                icons,
+                parent,
+                player,
+                id,
+                character.items[&id].clone(),
+            )
+        })
         .collect()
 }
 
@@ -465,6 +515,9 @@ pub struct SuggestionStar(pub Entity);
 #[derive(Debug, Component, Deref)]
 pub struct OwnedText(pub Entity);
 
+#[derive(Debug, Component, Deref)]
+pub struct BuildOrderText(pub Entity);
+
 fn setup_shop_item(
     commands: &mut Commands,
     icons: &Icons,
@@ -475,6 +528,7 @@ fn setup_shop_item(
 ) -> Entity {
     let mut owned_text = None;
     let mut star = None;
+    let mut build_order_text = None;
 
     let image = commands
         .spawn((
@@ -491,6 +545,18 @@ fn setup_shop_item(
         .with_children(|cb| {
             star = Some(cb.spawn(fav_star(icons)).id());
             owned_text = Some(cb.spawn(Text("".into())).id());
+            build_order_text = Some(
+                cb.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        top: Val::Px(0.0),
+                        ..default()
+                    },
+                    Text("".into()),
+                ))
+                .id(),
+            );
         })
         .id();
 
@@ -506,6 +572,7 @@ fn setup_shop_item(
             ShopItem(id),
             SuggestionStar(star.unwrap()),
             OwnedText(owned_text.unwrap()),
+            BuildOrderText(build_order_text.unwrap()),
             Owner(player),
             ChildOf(parent),
         ))
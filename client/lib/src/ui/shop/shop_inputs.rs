@@ -1,25 +1,54 @@
-use bevy::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
 use characters::{Character, Inventory};
-use foundation::{Controllers, InputStream, MenuInput, Owner, Player};
+use foundation::{
+    BuildOrders, Characters, Controllers, EconomyLedger, Icons, InputStream, ItemId, MatchRules,
+    MenuInput, Owner, Player, Players, RoundLog, ShopMode, Transaction,
+};
 
-use super::{setup_shop::ShopItem, shops_resource::Shop, Shops, SHOP_COLUMNS};
+use crate::ui::Notifications;
 
+use super::{
+    setup_shop::{restock, ShopItem},
+    shop_stock::{shop_stock, taken},
+    shops_resource::Shop,
+    Shops, SHOP_COLUMNS,
+};
+
+#[allow(clippy::too_many_arguments)]
 pub fn navigate_shop(
-    mut players: Query<(&Player, &mut Inventory, &Character)>,
+    mut commands: Commands,
+    mut query: Query<(&mut Inventory, &Character)>,
     slots: Query<(Entity, &Owner, Option<&ShopItem>)>,
     mut shops: ResMut<Shops>,
     input_stream: Res<InputStream>,
     controllers: Res<Controllers>,
+    players: Res<Players>,
+    icons: Res<Icons>,
+    rules: Res<MatchRules>,
+    round_log: Res<RoundLog>,
+    characters: Res<Characters>,
+    mut build_orders: ResMut<BuildOrders>,
+    mut notifications: ResMut<Notifications>,
+    mut ledger: ResMut<EconomyLedger>,
 ) {
     let evs = input_stream.menu_events.clone();
-    for (player, mut inventory, character) in &mut players {
-        let shop = shops.get_mut_shop(player);
+    let rounds_played = round_log.rounds_played();
+    for player in [Player::One, Player::Two] {
+        let shop = shops.get_mut_shop(&player);
 
         if shop.closed {
             continue;
         }
 
-        let input_device = controllers.get_handle(*player);
+        // Opponent is needed for the draft
+        let [(mut inventory, character), (opponent, _)] = query
+            .get_many_mut([players.get(player), players.get(player.other())])
+            .unwrap();
+
+        let input_device = controllers.get_handle(player);
+        let mut record = |transaction, amount| {
+            ledger.record(player, rounds_played, transaction, amount);
+        };
 
         for ev in &evs {
             if ev.player_handle != input_device {
@@ -31,14 +60,165 @@ pub fn navigate_shop(
                 MenuInput::Down => move_selection(shop, Down),
                 MenuInput::Left => move_selection(shop, Left),
                 MenuInput::Right => move_selection(shop, Right),
-                MenuInput::Accept => buy(shop, &mut inventory, character, &slots),
-                MenuInput::Cancel => sell(shop, &mut inventory, character, &slots),
+                // Build orders are local, the other peer wouldn't know what to buy
+                MenuInput::Select | MenuInput::RightShoulder if controllers.online() => {
+                    notifications.add(player, "Build orders are offline only".into());
+                }
+                MenuInput::Select => {
+                    shop.editing_build_order = !shop.editing_build_order;
+                }
+                MenuInput::Accept if shop.editing_build_order => {
+                    if let Some(id) = selected_item(shop, &slots) {
+                        build_orders.add(characters.get(player), id);
+                        build_orders.save();
+                    }
+                }
+                MenuInput::Cancel if shop.editing_build_order => {
+                    if let Some(id) = selected_item(shop, &slots) {
+                        build_orders.remove(characters.get(player), id);
+                        build_orders.save();
+                    }
+                }
+                MenuInput::Accept => {
+                    if let Some(id) =
+                        buy(shop, &mut inventory, &opponent, character, &slots, &rules)
+                    {
+                        record(
+                            Transaction::Purchase(id),
+                            -(character.items[&id].cost as i64),
+                        );
+                    }
+                }
+                MenuInput::Cancel => {
+                    if let Some((id, gained)) =
+                        sell(shop, &mut inventory, character, &slots, &rules)
+                    {
+                        record(Transaction::Sale(id), gained as i64);
+                    }
+                }
                 MenuInput::Secondary => shop.closed = true,
+                MenuInput::Tertiary => {
+                    if !can_reroll(&inventory, &rules) {
+                        continue;
+                    }
+
+                    let cost = rules.reroll_cost.unwrap();
+                    inventory.money -= cost;
+                    record(Transaction::Reroll, -(cost as i64));
+                    shop.rerolls += 1;
+
+                    let seed = rules.shop_seed(player, rounds_played, shop.rerolls);
+                    restock(
+                        &mut commands,
+                        &icons,
+                        shop,
+                        player,
+                        character,
+                        shop_stock(character, &inventory, &opponent, &rules, seed),
+                    );
+
+                    // New slots don't exist until the commands are applied
+                    break;
+                }
+                MenuInput::LeftShoulder => {
+                    if let Some(id) = undo(shop, &mut inventory, character) {
+                        record(Transaction::Refund(id), character.items[&id].cost as i64);
+                    }
+                }
+                MenuInput::RightShoulder => {
+                    let stock: Vec<ItemId> = shop
+                        .components
+                        .grid_items
+                        .iter()
+                        .filter_map(|slot| Some(**slots.get(*slot).ok()?.2?))
+                        .collect();
+
+                    for id in quick_buy(
+                        build_orders.get(characters.get(player)),
+                        &stock,
+                        &mut inventory,
+                        &opponent,
+                        character,
+                        &rules,
+                    ) {
+                        shop.purchases.push(id);
+                        record(
+                            Transaction::Purchase(id),
+                            -(character.items[&id].cost as i64),
+                        );
+                    }
+                }
             };
         }
     }
 }
 
+/// Goes down the build order, skipping what is already owned and stopping at the first item that is too expensive
+fn quick_buy(
+    order: &[ItemId],
+    stock: &[ItemId],
+    inventory: &mut Inventory,
+    opponent: &Inventory,
+    character: &Character,
+    rules: &MatchRules,
+) -> Vec<ItemId> {
+    let mut copies = HashMap::new();
+    let mut bought = vec![];
+
+    for id in order {
+        let wanted = copies.entry(*id).or_insert(0);
+        *wanted += 1;
+
+        let Some(item) = character.items.get(id) else {
+            continue;
+        };
+
+        if inventory.count(*id) >= *wanted || !stock.contains(id) {
+            continue;
+        }
+
+        if item.cost > inventory.money {
+            // Saving up for this one
+            break;
+        }
+
+        if inventory.can_buy(*id, item) && !taken(rules, inventory, opponent, *id) {
+            inventory.buy(*id, item.clone());
+            bought.push(*id);
+        }
+    }
+
+    bought
+}
+
+/// Last purchase of this shopping phase back for the full price
+fn undo(shop: &mut Shop, inventory: &mut Inventory, character: &Character) -> Option<ItemId> {
+    // Selling a component also sells the upgrades, those can't be undone anymore
+    while let Some(id) = shop.purchases.pop() {
+        if inventory.contains(id) {
+            inventory.refund(id, &character.items[&id]);
+            return Some(id);
+        }
+    }
+
+    None
+}
+
+fn selected_item(
+    shop: &Shop,
+    slots: &Query<(Entity, &Owner, Option<&ShopItem>)>,
+) -> Option<ItemId> {
+    let (_, _, shop_item) = slots.get(shop.get_selected_slot()?).ok()?;
+    Some(**shop_item?)
+}
+
+fn can_reroll(inventory: &Inventory, rules: &MatchRules) -> bool {
+    match (rules.shop_mode, rules.reroll_cost) {
+        (ShopMode::Random(_), Some(cost)) => inventory.money >= cost,
+        _ => false,
+    }
+}
+
 enum CardinalDiretion {
     Up,
     Down,
@@ -103,32 +283,142 @@ fn move_selection(shop: &mut Shop, direction: CardinalDiretion) {
 }
 
 fn buy(
-    shop: &Shop,
+    shop: &mut Shop,
     inventory: &mut Inventory,
+    opponent: &Inventory,
     character: &Character,
     slots: &Query<(Entity, &Owner, Option<&ShopItem>)>,
-) {
-    let selected_slot = shop.get_selected_slot();
+    rules: &MatchRules,
+) -> Option<ItemId> {
+    let selected_slot = shop.get_selected_slot()?;
     let (_, _, selected_item) = slots.get(selected_slot).unwrap();
     let shop_item = selected_item.unwrap();
 
     let item = character.items.get(&shop_item.0).unwrap().clone();
-    if inventory.can_buy(shop_item.0, &item) {
-        inventory.buy(**shop_item, item)
+    if !inventory.can_buy(shop_item.0, &item) || taken(rules, inventory, opponent, shop_item.0) {
+        return None;
     }
+
+    inventory.buy(**shop_item, item);
+    shop.purchases.push(**shop_item);
+    Some(**shop_item)
 }
 
 fn sell(
-    shop: &Shop,
+    shop: &mut Shop,
     inventory: &mut Inventory,
     character: &Character,
     slots: &Query<(Entity, &Owner, Option<&ShopItem>)>,
-) {
-    let selected_slot = shop.get_selected_slot();
+    rules: &MatchRules,
+) -> Option<(ItemId, usize)> {
+    let selected_slot = shop.get_selected_slot()?;
     let (_, _, selected_item) = slots.get(selected_slot).unwrap();
     let shop_item = selected_item.unwrap();
 
-    if inventory.contains(shop_item.0) {
-        inventory.sell(character, shop_item.0);
+    if !inventory.contains(shop_item.0) {
+        return None;
+    }
+
+    // Upgrades depending on it get sold too
+    let money_before = inventory.money;
+    inventory.sell(character, shop_item.0, rules);
+
+    // Sold at a loss, can't be undone for a full refund anymore
+    if let Some(index) = shop.purchases.iter().rposition(|id| *id == shop_item.0) {
+        shop.purchases.remove(index);
+    }
+
+    Some((shop_item.0, inventory.money - money_before))
+}
+
+#[cfg(test)]
+mod test {
+    use foundation::CharacterId;
+
+    use super::*;
+    use crate::ui::shop::shops_resource::ShopComponents;
+
+    #[test]
+    fn quick_buy_follows_the_order() {
+        let character = Character::from(CharacterId::Ronin);
+        let stock: Vec<ItemId> = character.items.keys().copied().collect();
+        let mut inventory = Inventory {
+            money: character.items[&ItemId::ThumbTacks].cost * 2,
+            ..default()
+        };
+
+        let bought = quick_buy(
+            &[ItemId::ThumbTacks, ItemId::ThumbTacks, ItemId::ThumbTacks],
+            &stock,
+            &mut inventory,
+            &Inventory::default(),
+            &character,
+            &MatchRules::default(),
+        );
+
+        // Money runs out on the third
+        assert_eq!(bought, vec![ItemId::ThumbTacks, ItemId::ThumbTacks]);
+        assert_eq!(inventory.money, 0);
+    }
+
+    #[test]
+    fn quick_buy_saves_up() {
+        let character = Character::from(CharacterId::Ronin);
+        let stock: Vec<ItemId> = character.items.keys().copied().collect();
+        let mut inventory = Inventory {
+            money: 0,
+            ..default()
+        };
+
+        assert!(quick_buy(
+            &[ItemId::Boots],
+            &stock,
+            &mut inventory,
+            &Inventory::default(),
+            &character,
+            &MatchRules::default(),
+        )
+        .is_empty());
+
+        // Not in stock is skipped
+        assert!(quick_buy(
+            &[ItemId::Boots],
+            &[],
+            &mut Inventory::default(),
+            &Inventory::default(),
+            &character,
+            &MatchRules::default(),
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn navigating_an_empty_shop() {
+        let mut shop = Shop {
+            components: ShopComponents {
+                countdown: Entity::PLACEHOLDER,
+                countdown_text: Entity::PLACEHOLDER,
+                big_icon: Entity::PLACEHOLDER,
+                item_name: Entity::PLACEHOLDER,
+                explanation: Entity::PLACEHOLDER,
+                cost: Entity::PLACEHOLDER,
+                dependencies: Entity::PLACEHOLDER,
+                stats: Entity::PLACEHOLDER,
+                grid: Entity::PLACEHOLDER,
+                grid_items: vec![],
+            },
+            selected_index: 0,
+            max_index: 0,
+            closed: false,
+            rerolls: 0,
+            purchases: vec![],
+            editing_build_order: false,
+        };
+
+        for direction in [Up, Down, Left, Right] {
+            move_selection(&mut shop, direction);
+            assert_eq!(shop.selected_index, 0);
+            assert_eq!(shop.get_selected_slot(), None);
+        }
     }
 }
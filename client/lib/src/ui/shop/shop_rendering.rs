@@ -1,10 +1,9 @@
 use bevy::prelude::*;
 use characters::{Character, Inventory, ItemCategory};
 use foundation::{
-    Clock, Icons, MatchState, Owner, Player, Players, RoundLog, FPS, ITEM_SLOT_COMPONENT_COLOR,
-    ITEM_SLOT_DEFAULT_COLOR, ITEM_SLOT_DISABLED_COLOR, ITEM_SLOT_HIGHLIGHT_COLOR,
-    ITEM_SLOT_OWNED_COLOR, ITEM_SLOT_UPGRADE_COLOR, POST_SHOP_DURATION, PRE_ROUND_DURATION,
-    SELL_RETURN,
+    BuildOrders, Characters, Clock, Icons, MatchRules, MatchState, Owner, Player, Players,
+    RoundLog, FPS, ITEM_SLOT_COMPONENT_COLOR, ITEM_SLOT_DEFAULT_COLOR, ITEM_SLOT_DISABLED_COLOR,
+    ITEM_SLOT_HIGHLIGHT_COLOR, ITEM_SLOT_OWNED_COLOR, ITEM_SLOT_UPGRADE_COLOR, PRE_ROUND_DURATION,
 };
 
 use crate::{
@@ -14,7 +13,8 @@ use crate::{
 };
 
 use super::{
-    setup_shop::{OwnedText, ShopItem, ShopMoney, ShopScore, SuggestionStar},
+    setup_shop::{BuildOrderText, OwnedText, ShopItem, ShopMoney, ShopScore, SuggestionStar},
+    shop_stock::taken,
     Shops,
 };
 
@@ -38,6 +38,7 @@ pub fn update_top_bar_moneys(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn update_slot_visuals(
     player_query: Query<(&Inventory, &Character, &Player)>,
     item_query: Query<(
@@ -47,18 +48,28 @@ pub fn update_slot_visuals(
         &Children,
         &OwnedText,
         &SuggestionStar,
+        &BuildOrderText,
     )>,
     mut colors: Query<&mut BackgroundColor>,
     mut texts: Query<&mut Text>,
     mut visibilities: Query<&mut Visibility>,
     shops: Res<Shops>,
+    rules: Res<MatchRules>,
+    characters: Res<Characters>,
+    build_orders: Res<BuildOrders>,
 ) {
     for (inventory, character, player) in &player_query {
+        let (opponent, _, _) = player_query
+            .iter()
+            .find(|(_, _, other)| *other != player)
+            .unwrap();
         let shop = shops.get_shop(player);
-        let selected_slot = shop.get_selected_slot();
+        let Some(selected_slot) = shop.get_selected_slot() else {
+            continue;
+        };
         let selected_item_id = item_query
             .iter()
-            .find_map(|(shop_item, _, e, _, _, _)| {
+            .find_map(|(shop_item, _, e, _, _, _, _)| {
                 if e == selected_slot {
                     Some(**shop_item)
                 } else {
@@ -68,7 +79,16 @@ pub fn update_slot_visuals(
             .unwrap();
         let selected_item = character.items.get(&selected_item_id).unwrap();
 
-        for (shop_item, owner, item_entity, children, owned_text, suggestion_star) in &item_query {
+        for (
+            shop_item,
+            owner,
+            item_entity,
+            children,
+            owned_text,
+            suggestion_star,
+            build_order_text,
+        ) in &item_query
+        {
             if *player != owner.0 {
                 continue;
             }
@@ -107,6 +127,8 @@ pub fn update_slot_visuals(
                         format!("{item_count}/{}", item.max_stack)
                     },
                 )
+            } else if taken(&rules, inventory, opponent, item_id) {
+                (ITEM_SLOT_DISABLED_COLOR.into(), "Taken".into())
             } else {
                 (
                     if inventory.can_buy(item_id, item) {
@@ -118,6 +140,13 @@ pub fn update_slot_visuals(
                 )
             };
 
+            texts.get_mut(**build_order_text).unwrap().0 = build_orders
+                .positions(characters.get(*player), item_id)
+                .into_iter()
+                .map(|position| format!("#{position}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+
             *visibilities.get_mut(**suggestion_star).unwrap() = if item.suggested {
                 Visibility::Inherited
             } else {
@@ -137,15 +166,19 @@ pub fn update_info_panel(
     shops: Res<Shops>,
     characters: Query<(&Character, &Inventory)>,
     players: Res<Players>,
+    rules: Res<MatchRules>,
+    character_ids: Res<Characters>,
+    build_orders: Res<BuildOrders>,
 ) {
     for player in [Player::One, Player::Two] {
         let shop = shops.get_shop(&player);
-        let active_slot = shop.get_selected_slot();
+        let Some(active_slot) = shop.get_selected_slot() else {
+            continue;
+        };
         let slot = slots.get(active_slot).unwrap();
         let item_id = slot.0;
 
         let (character, inventory) = characters.get(players.get(player)).unwrap();
-        let item_name = item_id.display_name();
         let item = character.items.get(&item_id).unwrap();
 
         let mut allowed_ops = vec![];
@@ -157,33 +190,61 @@ pub fn update_info_panel(
         if inventory.contains(item_id) {
             allowed_ops.push((
                 "Sell",
-                (SELL_RETURN * (inventory.sell_price(character, item_id) as f32)) as usize,
+                rules.sell_value(inventory.sell_price(character, item_id)),
             ));
         };
 
+        // Nothing to preview if it can't be bought
+        let stat_changes = if inventory.has_space_for(item_id, item) {
+            let base = character.base_stats;
+            base.combine(&inventory.get_effects(character))
+                .changes(&base.combine(&inventory.effects_with(character, item_id)))
+                .join(", ")
+        } else {
+            "".to_string()
+        };
+
         let price_line = allowed_ops
             .into_iter()
             .map(|(op, amount)| format!("{op} for ${amount}"))
             .reduce(|a, b| format!("{a}, {b}"))
             .unwrap();
 
+        // The panel doubles as the build order menu
+        let (explanation, price_line) = if shop.editing_build_order {
+            (
+                build_orders
+                    .get(character_ids.get(player))
+                    .iter()
+                    .filter_map(|id| character.items.get(id))
+                    .map(|item| item.name.to_owned())
+                    .intersperse(" > ".to_string())
+                    .collect(),
+                "Build order: Accept to add, Cancel to remove".to_string(),
+            )
+        } else {
+            (item.explanation.to_owned(), price_line)
+        };
+
         // Update texts
         for (entity, section, content) in [
-            (shop.components.item_name, 1, item_name),
-            (shop.components.explanation, 1, item.explanation.to_owned()),
+            (shop.components.item_name, 1, item.name.to_owned()),
+            (shop.components.explanation, 1, explanation),
             (shop.components.cost, 1, price_line),
             (
                 shop.components.dependencies,
                 1,
                 if let ItemCategory::Upgrade(deps) = &item.category {
                     deps.iter()
-                        .map(|id| id.display_name())
+                        .filter_map(|id| character.items.get(id))
+                        .map(|item| item.name.to_owned())
                         .intersperse(", ".to_string())
                         .collect()
                 } else {
                     "".to_string()
                 },
             ),
+            (shop.components.stats, 1, stat_changes),
         ] {
             let (entity, mut visibility) = visibilities.get_mut(entity).unwrap();
             if content.is_empty() {
@@ -217,6 +278,7 @@ pub fn handle_shop_ending(
     mut announcer: ResMut<Announcer>,
     round_log: Res<RoundLog>,
     clock: Res<Clock>,
+    rules: Res<MatchRules>,
 ) {
     commands.run_system_cached(camera::reset_camera);
     let round_num = round_log.rounds_played() + 1;
@@ -251,7 +313,7 @@ pub fn handle_shop_ending(
                     .unwrap()
                     .0 = secs_left.to_string();
             } else {
-                *local_timer = Some(clock.frame + (FPS * POST_SHOP_DURATION) as usize);
+                *local_timer = Some(clock.frame + (FPS * rules.post_shop_duration) as usize);
                 *countdown_roots.get_mut(shop.components.countdown).unwrap() =
                     Visibility::Inherited;
             }
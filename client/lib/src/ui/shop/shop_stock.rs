@@ -0,0 +1,140 @@
+use characters::{Character, Inventory};
+use foundation::{ItemId, MatchRules, ShopMode};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// In a draft, whoever buys an item first gets it
+pub fn taken(rules: &MatchRules, inventory: &Inventory, opponent: &Inventory, id: ItemId) -> bool {
+    rules.draft && opponent.contains(id) && !inventory.contains(id)
+}
+
+/// Items on display, owned items are always there so they can be sold
+pub fn shop_stock(
+    character: &Character,
+    inventory: &Inventory,
+    opponent: &Inventory,
+    rules: &MatchRules,
+    seed: u64,
+) -> Vec<ItemId> {
+    let mut ids: Vec<ItemId> = character.items.keys().copied().collect();
+    // Hash map order is not the same for both peers
+    ids.sort();
+
+    let (mut stock, mut pool): (Vec<_>, Vec<_>) = ids
+        .into_iter()
+        .filter(|id| !character.items[id].banned && !taken(rules, inventory, opponent, *id))
+        .partition(|id| inventory.contains(*id));
+
+    if let ShopMode::Random(amount) = rules.shop_mode {
+        pool.shuffle(&mut StdRng::seed_from_u64(seed));
+        pool.truncate(amount);
+    }
+
+    stock.extend(pool);
+    stock
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::prelude::*;
+    use foundation::{CharacterId, ItemKind};
+
+    use super::*;
+
+    fn random_rules() -> MatchRules {
+        MatchRules {
+            shop_mode: ShopMode::Random(5),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn full_shop_has_everything() {
+        let character = Character::from(CharacterId::Ronin);
+        let stock = shop_stock(
+            &character,
+            &Inventory::default(),
+            &Inventory::default(),
+            &MatchRules::default(),
+            0,
+        );
+
+        assert_eq!(stock.len(), character.items.len());
+    }
+
+    #[test]
+    fn random_shop_is_deterministic() {
+        let character = Character::from(CharacterId::Ronin);
+        let rules = random_rules();
+        let inventory = Inventory::default();
+
+        let stock = shop_stock(&character, &inventory, &inventory, &rules, 1);
+        assert_eq!(stock.len(), 5);
+        assert_eq!(
+            stock,
+            shop_stock(&character, &inventory, &inventory, &rules, 1)
+        );
+        assert_ne!(
+            stock,
+            shop_stock(&character, &inventory, &inventory, &rules, 2)
+        );
+    }
+
+    #[test]
+    fn owned_items_stay_in_random_shop() {
+        let character = Character::from(CharacterId::Ronin);
+        let mut inventory = Inventory::default();
+        inventory.items.insert(ItemId::Boots, 1);
+
+        for seed in 0..10 {
+            let stock = shop_stock(
+                &character,
+                &inventory,
+                &Inventory::default(),
+                &random_rules(),
+                seed,
+            );
+            assert_eq!(stock.len(), 6);
+            assert!(stock.contains(&ItemId::Boots));
+        }
+    }
+
+    #[test]
+    fn banned_items_are_hidden() {
+        let mut character = Character::from(CharacterId::Ronin);
+        let rules = MatchRules {
+            banned_kinds: vec![ItemKind::Upgrade],
+            ..default()
+        };
+        character.ban_items(&rules);
+
+        let stock = shop_stock(
+            &character,
+            &Inventory::default(),
+            &Inventory::default(),
+            &rules,
+            0,
+        );
+        assert!(!stock.is_empty());
+        assert!(stock
+            .iter()
+            .all(|id| character.items[id].category.kind() != ItemKind::Upgrade));
+    }
+
+    #[test]
+    fn drafted_items_are_gone() {
+        let character = Character::from(CharacterId::Ronin);
+        let rules = MatchRules {
+            draft: true,
+            ..default()
+        };
+        let mut opponent = Inventory::default();
+        opponent.items.insert(ItemId::Boots, 1);
+
+        let stock = shop_stock(&character, &Inventory::default(), &opponent, &rules, 0);
+        assert!(!stock.contains(&ItemId::Boots));
+        assert_eq!(stock.len(), character.items.len() - 1);
+
+        // Both owning it doesn't take it away from either
+        assert!(!taken(&rules, &opponent, &opponent, ItemId::Boots));
+    }
+}
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use foundation::Player;
+use foundation::{ItemId, Player};
 
 #[derive(Default)]
 pub struct ShopComponentsBuilder {
@@ -13,8 +13,10 @@ pub struct ShopComponentsBuilder {
     pub explanation: Option<Entity>,
     pub cost: Option<Entity>,
     pub dependencies: Option<Entity>,
+    pub stats: Option<Entity>,
 
     // Bottom
+    pub grid: Option<Entity>,
     pub grid_items: Vec<Entity>,
 }
 impl ShopComponentsBuilder {
@@ -27,6 +29,8 @@ impl ShopComponentsBuilder {
             explanation: self.explanation.expect("fully built UI"),
             cost: self.cost.expect("fully built UI"),
             dependencies: self.dependencies.expect("fully built UI"),
+            stats: self.stats.expect("fully built UI"),
+            grid: self.grid.expect("fully built UI"),
             grid_items: self.grid_items,
         }
     }
@@ -44,8 +48,10 @@ pub struct ShopComponents {
     pub explanation: Entity,
     pub cost: Entity,
     pub dependencies: Entity,
+    pub stats: Entity,
 
     // Bottom
+    pub grid: Entity,
     pub grid_items: Vec<Entity>,
 }
 
@@ -55,10 +61,17 @@ pub struct Shop {
     pub selected_index: usize,
     pub max_index: usize, // Duplicated here for ease of access
     pub closed: bool,
+    /// This shopping phase
+    pub rerolls: usize,
+    /// This shopping phase, for undoing
+    pub purchases: Vec<ItemId>,
+    /// Accept and cancel edit the build order instead of buying and selling
+    pub editing_build_order: bool,
 }
 impl Shop {
-    pub fn get_selected_slot(&self) -> Entity {
-        self.components.grid_items[self.selected_index]
+    /// None if nothing is in stock
+    pub fn get_selected_slot(&self) -> Option<Entity> {
+        self.components.grid_items.get(self.selected_index).copied()
     }
 }
 
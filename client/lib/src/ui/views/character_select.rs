@@ -1,14 +1,16 @@
 use crate::{
-    assets::Fonts,
+    assets::{CharacterDefinitions, Fonts},
     entity_management::VisibleInStates,
     networking,
     ui::{SharedVerticalNav, VerticalMenuNavigation},
 };
 use bevy::prelude::*;
+use characters::CharacterDefinition;
 use foundation::{
-    CharacterId, Characters, Controllers, GameState, InputStream, LocalCharacter, LocalController,
-    LocalState, MatchState, MenuInput, OnlineState, Player, SoundRequest,
-    CHARACTER_SELECT_HIGHLIGHT_TEXT_COLOR, GENERIC_TEXT_COLOR, VERTICAL_MENU_OPTION_BACKGROUND,
+    CharacterId, Characters, ControlSchemes, Controllers, GameState, InputStream, LocalCharacter,
+    LocalController, LocalState, MatchState, MenuInput, OnlineState, Palettes, Player,
+    SoundRequest, CHARACTER_SELECT_HIGHLIGHT_TEXT_COLOR, GENERIC_TEXT_COLOR,
+    VERTICAL_MENU_OPTION_BACKGROUND,
 };
 use strum::IntoEnumIterator;
 
@@ -133,6 +135,10 @@ pub fn navigate_character_select(
     mut match_state: ResMut<NextState<MatchState>>,
     input_stream: ResMut<InputStream>,
     local_controller: Option<Res<LocalController>>,
+    mut schemes: ResMut<ControlSchemes>,
+    mut palettes: ResMut<Palettes>,
+    definition_handles: Res<CharacterDefinitions>,
+    definitions: Res<Assets<CharacterDefinition>>,
 ) {
     for ev in input_stream.menu_events.clone() {
         let (player, is_online) = if let Some(ref lc) = local_controller {
@@ -153,9 +159,35 @@ pub fn navigate_character_select(
             )
         };
 
+        let hovered = match player {
+            Player::One => nav.p1_select.selected,
+            Player::Two => nav.p2_select.selected,
+        };
+        let palette_count = palette_names(
+            *options.get(hovered).unwrap(),
+            &definition_handles,
+            &definitions,
+        )
+        .len()
+        .max(1);
+
         match ev.event {
-            MenuInput::Up => nav.up(player),
-            MenuInput::Down => nav.down(player),
+            // Palettes differ between characters, so start over from the default
+            MenuInput::Up if !nav.locked(player) => {
+                nav.up(player);
+                palettes.reset(player);
+            }
+            MenuInput::Down if !nav.locked(player) => {
+                nav.down(player);
+                palettes.reset(player);
+            }
+            MenuInput::Left if !nav.locked(player) => palettes.previous(player, palette_count),
+            MenuInput::Right if !nav.locked(player) => palettes.next(player, palette_count),
+            MenuInput::Secondary => {
+                if !nav.locked(player) {
+                    schemes.toggle(player);
+                }
+            }
             MenuInput::Accept => {
                 commands.trigger(SoundRequest::menu_transition());
 
@@ -199,17 +231,45 @@ pub fn navigate_character_select(
     }
 }
 
+fn palette_names(
+    character: CharacterId,
+    handles: &CharacterDefinitions,
+    definitions: &Assets<CharacterDefinition>,
+) -> Vec<String> {
+    handles
+        .get(&character)
+        .and_then(|handle| definitions.get(handle))
+        .map(|definition| {
+            definition
+                .palettes
+                .iter()
+                .map(|palette| palette.name.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_character_select_visuals(
-    mut indicators: Query<(&mut Visibility, &mut TextColor, &CharacterHoverIndicator)>,
+    mut indicators: Query<(
+        &mut Visibility,
+        &mut TextColor,
+        &mut Text,
+        &CharacterHoverIndicator,
+    )>,
     navigator: Res<CharacterSelectNav>,
     options: Query<&CharacterId>,
     local_controller: Option<Res<LocalController>>,
+    schemes: Res<ControlSchemes>,
+    palettes: Res<Palettes>,
+    definition_handles: Res<CharacterDefinitions>,
+    definitions: Res<Assets<CharacterDefinition>>,
 ) {
     let [p1_char, p2_char] = options
         .get_many([navigator.p1_select.selected, navigator.p2_select.selected])
         .unwrap();
 
-    for (mut visibility, mut text_color, indicator) in &mut indicators {
+    for (mut visibility, mut text_color, mut text, indicator) in &mut indicators {
         let (locked, character) = match indicator.player {
             Player::One => (navigator.p1_locked, p1_char),
             Player::Two => (navigator.p2_locked, p2_char),
@@ -224,6 +284,17 @@ pub fn update_character_select_visuals(
             Visibility::Hidden
         };
 
+        let palette = palette_names(*character, &definition_handles, &definitions)
+            .get(palettes.get(indicator.player))
+            .cloned()
+            .unwrap_or_default();
+        text.0 = format!(
+            "P{} ({}, {})",
+            usize::from(indicator.player),
+            schemes.get(indicator.player),
+            palette
+        );
+
         text_color.0 = if locked {
             CHARACTER_SELECT_HIGHLIGHT_TEXT_COLOR
         } else {
@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use foundation::{
-    Controllers, GameState, InputDevice, InputStream, LocalState, MenuInput, Player, SoundRequest,
-    CONTROLLER_ASSIGNMENT_SIDE_COLOR, KEYBOARD_MAGIC_CONSTANT,
+    Controllers, GameState, InputDevice, InputStream, InputTimings, LocalState, MenuInput, Player,
+    SoundRequest, StickConfig, WagArgs, CONTROLLER_ASSIGNMENT_SIDE_COLOR, KEYBOARD_MAGIC_CONSTANT,
 };
 
 use crate::{assets::Fonts, entity_management::VisibleInStates};
@@ -12,6 +12,7 @@ use super::setup_view_title;
 pub struct ControllerAssignment {
     p1: Option<InputDevice>,
     p2: Option<InputDevice>,
+    timings: InputTimings,
 }
 impl ControllerAssignment {
     fn left(&mut self, player_handle: InputDevice) {
@@ -30,6 +31,16 @@ impl ControllerAssignment {
         }
     }
 
+    fn player(&self, player_handle: InputDevice) -> Option<Player> {
+        if self.p1 == Some(player_handle) {
+            Some(Player::One)
+        } else if self.p2 == Some(player_handle) {
+            Some(Player::Two)
+        } else {
+            None
+        }
+    }
+
     fn is_complete(&self) -> bool {
         self.p1.is_some() && self.p2.is_some()
     }
@@ -41,7 +52,7 @@ pub struct SelectedController(Player);
 #[derive(Debug, Component)]
 pub struct FreeControllers;
 
-pub fn setup_controller_assignment(mut commands: Commands, fonts: Res<Fonts>) {
+pub fn setup_controller_assignment(mut commands: Commands, fonts: Res<Fonts>, args: Res<WagArgs>) {
     commands
         .spawn((
             Node {
@@ -64,7 +75,10 @@ pub fn setup_controller_assignment(mut commands: Commands, fonts: Res<Fonts>) {
             setup_areas(cb, &fonts);
         });
 
-    commands.insert_resource(ControllerAssignment::default());
+    commands.insert_resource(ControllerAssignment {
+        timings: InputTimings::uniform(args.input_timing),
+        ..default()
+    });
 }
 
 fn setup_areas(root: &mut ChildSpawnerCommands, fonts: &Fonts) {
@@ -139,17 +153,40 @@ pub fn navigate_controller_assignment_menu(
     mut ca: ResMut<ControllerAssignment>,
     input_stream: ResMut<InputStream>,
     mut state: ResMut<NextState<GameState>>,
+    mut stick_config: ResMut<StickConfig>,
 ) {
     for ev in input_stream.menu_events.clone() {
         match ev.event {
             MenuInput::Right => ca.right(ev.player_handle),
             MenuInput::Left => ca.left(ev.player_handle),
+            MenuInput::Secondary => {
+                if matches!(ev.player_handle, InputDevice::Controller(_)) {
+                    stick_config.cycle_source(ev.player_handle);
+                }
+            }
+            MenuInput::LeftShoulder => {
+                if matches!(ev.player_handle, InputDevice::Controller(_)) {
+                    stick_config.cycle_dead_zone(ev.player_handle);
+                }
+            }
+            MenuInput::RightShoulder => {
+                if matches!(ev.player_handle, InputDevice::Controller(_)) {
+                    stick_config.cycle_diagonal_width(ev.player_handle);
+                }
+            }
+            MenuInput::Tertiary => {
+                // Timing is per player, so the controller has to be on a side first
+                if let Some(player) = ca.player(ev.player_handle) {
+                    ca.timings.cycle(player);
+                }
+            }
             MenuInput::Accept => {
                 if ca.is_complete() {
                     commands.insert_resource(Controllers {
                         p1: ca.p1.unwrap(),
                         p2: ca.p2.unwrap(),
                     });
+                    commands.insert_resource(ca.timings);
 
                     state.set(GameState::Local(LocalState::CharacterSelect));
                     commands.trigger(SoundRequest::menu_transition());
@@ -167,6 +204,7 @@ pub fn navigate_controller_assignment_menu(
 #[derive(Debug, Component)]
 pub struct ControllerIcon;
 
+#[allow(clippy::too_many_arguments)]
 pub fn update_controller_assignment_menu_visuals(
     mut commands: Commands,
     ca: Res<ControllerAssignment>,
@@ -175,8 +213,9 @@ pub fn update_controller_assignment_menu_visuals(
     free_container: Query<Entity, With<FreeControllers>>,
     selected_controllers: Query<(Entity, &SelectedController)>,
     fonts: Res<Fonts>,
+    stick_config: Res<StickConfig>,
 ) {
-    if !ca.is_changed() {
+    if !ca.is_changed() && !stick_config.is_changed() {
         return;
     }
 
@@ -201,31 +240,36 @@ pub fn update_controller_assignment_menu_visuals(
         .into_iter()
         .chain(pads.iter().map(InputDevice::Controller).enumerate())
     {
+        let mut label = if index == KEYBOARD_MAGIC_CONSTANT {
+            "keyboard".into()
+        } else {
+            format!("{} ({})", index, stick_config.get(pad_id))
+        };
+        if let Some(player) = ca.player(pad_id) {
+            label += &format!(", {} timing", ca.timings.get(player));
+        }
+
         if ca.p1 == Some(pad_id) {
             commands
                 .entity(p1_selected)
-                .with_children(create_icon(index, &fonts));
+                .with_children(create_icon(label, &fonts));
         } else if ca.p2 == Some(pad_id) {
             commands
                 .entity(p2_selected)
-                .with_children(create_icon(index, &fonts));
+                .with_children(create_icon(label, &fonts));
         } else {
             commands
                 .entity(unused)
-                .with_children(create_icon(index, &fonts));
+                .with_children(create_icon(label, &fonts));
         }
     }
 }
 
-fn create_icon(id: usize, fonts: &Fonts) -> impl Fn(&mut ChildSpawnerCommands) {
+fn create_icon(label: String, fonts: &Fonts) -> impl Fn(&mut ChildSpawnerCommands) {
     let font = fonts.basic.clone();
     move |cb: &mut ChildSpawnerCommands| {
         cb.spawn((
-            Text::from(if id == KEYBOARD_MAGIC_CONSTANT {
-                "keyboard".into()
-            } else {
-                id.to_string()
-            }),
+            Text::from(label.clone()),
             TextFont {
                 font: font.clone(),
                 font_size: 40.0,
@@ -7,8 +7,8 @@ use crate::{
 };
 use bevy::prelude::*;
 use foundation::{
-    Clock, Controllers, GameResult, GameState, InputStream, MatchState, MenuInput, Player,
-    RoundLog, SoundRequest, CHARACTER_SELECT_HIGHLIGHT_TEXT_COLOR, GENERIC_TEXT_COLOR,
+    Clock, Controllers, EconomyLedger, GameResult, GameState, InputStream, MatchState, MenuInput,
+    Player, RoundLog, SoundRequest, CHARACTER_SELECT_HIGHLIGHT_TEXT_COLOR, GENERIC_TEXT_COLOR,
     VERTICAL_MENU_OPTION_BACKGROUND,
 };
 
@@ -17,6 +17,10 @@ use super::{setup_view_subtitle, setup_view_title};
 #[derive(Debug, Component)]
 pub struct MatchResultTextMarker;
 
+/// Money in and out over the match
+#[derive(Debug, Component)]
+pub struct EconomyBreakdown(Player);
+
 #[derive(Debug, Resource, Deref, DerefMut)]
 pub struct EndScreenNav(SharedVerticalNav);
 
@@ -64,6 +68,28 @@ pub fn setup_end_screen(mut commands: Commands, fonts: Res<Fonts>) {
             setup_view_title(cb, &fonts, "").insert(MatchResultTextMarker);
             setup_view_subtitle(cb, &fonts, "go next?");
 
+            cb.spawn((
+                Node {
+                    justify_content: JustifyContent::SpaceBetween,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                Name::new("Economy breakdown"),
+            ))
+            .with_children(|ccb| {
+                for player in [Player::One, Player::Two] {
+                    ccb.spawn((
+                        Text::default(),
+                        TextFont {
+                            font: fonts.basic.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        EconomyBreakdown(player),
+                    ));
+                }
+            });
+
             navigation = Some(VerticalMenuNavigation::from_buttons(
                 vec![
                     EndScreenOption::Rematch,
@@ -248,3 +274,32 @@ pub fn update_end_screen_visuals(
 
     result_text.into_inner().0 = format!("Player {} wins!", result.winner);
 }
+
+pub fn update_economy_breakdown(
+    mut texts: Query<(&mut Text, &EconomyBreakdown)>,
+    ledger: Res<EconomyLedger>,
+) {
+    for (mut text, EconomyBreakdown(player)) in &mut texts {
+        text.0 = std::iter::once(format!("Player {player} economy"))
+            .chain(
+                ledger
+                    .breakdown(*player)
+                    .into_iter()
+                    .map(|(category, amount)| format!("{category}: {}", dollars(amount))),
+            )
+            .chain(std::iter::once(format!(
+                "Left over: {}",
+                dollars(ledger.balance(*player))
+            )))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+}
+
+fn dollars(amount: i64) -> String {
+    if amount < 0 {
+        format!("-${}", -amount)
+    } else {
+        format!("${amount}")
+    }
+}
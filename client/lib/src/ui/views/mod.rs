@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use foundation::{
-    GameState, InCharacterSelect, LocalState, MatchState, RollbackSchedule, SystemStep,
+    ControlSchemes, GameState, InCharacterSelect, LocalState, MatchState, Palettes,
+    RollbackSchedule, SystemStep,
 };
 
 use crate::assets::Fonts;
@@ -50,13 +51,16 @@ impl Plugin for ViewsPlugin {
                 (
                     end_screen::navigate_end_screen,
                     end_screen::update_end_screen_visuals,
+                    end_screen::update_economy_breakdown,
                 )
                     .chain()
                     .run_if(in_state(MatchState::EndScreen)),
             )
                 .chain()
                 .in_set(SystemStep::Menus),
-        );
+        )
+        .init_resource::<ControlSchemes>()
+        .init_resource::<Palettes>();
     }
 }
 
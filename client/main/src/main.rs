@@ -11,23 +11,31 @@ fn main() {
     let base_size = 30.0;
 
     App::new()
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(if args.dev.is_some() {
-                Window {
-                    mode: WindowMode::Windowed,
-                    resizable: true,
-                    resolution: WindowResolution::new(base_size * 16.0, base_size * 9.0),
+        .add_plugins(
+            DefaultPlugins
+                .set(AssetPlugin {
+                    // Hot reloading for character definitions
+                    watch_for_changes_override: Some(args.hot_reload()),
                     ..default()
-                }
-            } else {
-                Window {
-                    mode: WindowMode::BorderlessFullscreen(MonitorSelection::Current),
-                    resizable: false,
+                })
+                .set(WindowPlugin {
+                    primary_window: Some(if args.dev.is_some() {
+                        Window {
+                            mode: WindowMode::Windowed,
+                            resizable: true,
+                            resolution: WindowResolution::new(base_size * 16.0, base_size * 9.0),
+                            ..default()
+                        }
+                    } else {
+                        Window {
+                            mode: WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+                            resizable: false,
+                            ..default()
+                        }
+                    }),
                     ..default()
-                }
-            }),
-            ..default()
-        }))
+                }),
+        )
         .add_plugins(Lib::with_args(args))
         // .add_plugins((
         //     LogDiagnosticsPlugin::default(),
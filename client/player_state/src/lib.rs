@@ -3,3 +3,6 @@ mod sub_state;
 
 mod player_state;
 pub use player_state::PlayerState;
+
+mod puppet_state;
+pub use puppet_state::PuppetState;
@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 
 use characters::{
-    ActionEvent, ActionTracker, Character, CharacterStateBoxes, Gauges, Inventory, Situation,
+    ActionEvent, ActionTracker, Character, CharacterStateBoxes, CounterHit, Gauges, Inventory,
+    Situation,
 };
 use foundation::{
-    ActionId, AnimationType, CancelType, CharacterFacing, Combo, Facing, SimpleState, Stats,
-    StatusCondition, StatusFlag,
+    ActionId, AnimationType, Armor, CancelType, CharacterFacing, Combo, Facing, ProjectileGuard,
+    SimpleState, Stats, StatusCondition, StatusFlag,
 };
 use input_parsing::InputParser;
 
@@ -79,6 +80,7 @@ impl PlayerState {
         };
         self.free_since = None;
         self.clear_cancel_windows();
+        self.clear_move_conditions();
     }
 
     pub fn set_transient(&mut self, action_id: ActionId) {
@@ -114,13 +116,17 @@ impl PlayerState {
         let mut evs = vec![];
 
         if let Some(trans) = self.transient.take() {
-            let trans_evs = (character.get_move(trans).unwrap().script)(&situation);
-            evs.extend(trans_evs);
+            if let Some(action) = character.get_move(trans) {
+                evs.extend((action.script)(&situation));
+            }
         };
 
         if let Some(tracker) = self.get_action_tracker() {
-            let action_evs = (character.get_move(tracker.action_id).unwrap().script)(&situation);
-            evs.extend(action_evs);
+            match character.get_move(tracker.action_id) {
+                Some(action) => evs.extend((action.script)(&situation)),
+                // The character was reloaded without this move
+                None => evs.push(ActionEvent::End),
+            }
         }
 
         evs
@@ -148,6 +154,7 @@ impl PlayerState {
             grounded: self.is_grounded(),
             tracker: self.get_action_tracker().cloned(),
             held_buttons: input_parser.get_pressed(),
+            pressed_buttons: input_parser.get_pressed_on(abs_frame),
             stick_position: input_parser.get_stick_pos(),
             status_flags: self.conditions.iter().map(|c| c.flag.clone()).collect(),
             position: player_position,
@@ -183,6 +190,31 @@ impl PlayerState {
         }
     }
 
+    pub fn register_attack(&mut self) {
+        if let Some(ref mut tracker) = self.get_action_tracker_mut() {
+            tracker.attacked = true;
+        }
+    }
+
+    // Attack startup is a counter hit, recovery is a punish and the active frames are neither
+    pub fn counter_hit(&self, character: &Character, attack_active: bool) -> Option<CounterHit> {
+        let tracker = self.get_action_tracker()?;
+        if !character
+            .get_move(tracker.action_id)
+            .is_some_and(|action| action.attack)
+        {
+            return None;
+        }
+
+        if !tracker.attacked {
+            Some(CounterHit::Counter)
+        } else if !attack_active {
+            Some(CounterHit::Punish)
+        } else {
+            None
+        }
+    }
+
     // Stun
     pub fn block(&mut self, recovery_frame: usize) {
         self.main = match &self.main {
@@ -202,6 +234,7 @@ impl PlayerState {
             other => panic!("Stunned while {other:?}"),
         };
         self.free_since = None;
+        self.clear_move_conditions();
     }
     pub fn recover(&mut self, frame: usize) {
         self.main = match self.main {
@@ -213,6 +246,7 @@ impl PlayerState {
         self.free_since = Some(frame);
         self.clear_cancel_windows();
         self.clear_comic_cancels();
+        self.clear_move_conditions();
     }
     pub fn unstun_frame(&self) -> Option<usize> {
         match self.main {
@@ -242,6 +276,7 @@ impl PlayerState {
     pub fn launch(&mut self) {
         self.main = MainState::Air(AirState::Freefall);
         self.free_since = None;
+        self.clear_move_conditions();
     }
     pub fn land(&mut self, frame: usize) {
         self.main = if matches!(self.main, MainState::Air(AirState::Freefall)) {
@@ -368,6 +403,53 @@ impl PlayerState {
         self.otg_since().is_some() || self.has_flag(StatusFlag::Intangible)
     }
 
+    pub fn armor(&self) -> Option<Armor> {
+        self.conditions.iter().find_map(|cond| {
+            if let StatusFlag::Armor(armor) = cond.flag {
+                Some(armor)
+            } else {
+                None
+            }
+        })
+    }
+    pub fn projectile_guard(&self) -> Option<ProjectileGuard> {
+        self.conditions.iter().find_map(|cond| {
+            if let StatusFlag::ProjectileGuard(guard) = cond.flag {
+                Some(guard)
+            } else {
+                None
+            }
+        })
+    }
+    /// Takes one hit off the armor, breaking it on the last one
+    pub fn use_armor(&mut self) {
+        let Some(index) = self
+            .conditions
+            .iter()
+            .position(|cond| matches!(cond.flag, StatusFlag::Armor(_)))
+        else {
+            return;
+        };
+
+        let StatusFlag::Armor(ref mut armor) = self.conditions[index].flag else {
+            unreachable!()
+        };
+
+        armor.hits = armor.hits.saturating_sub(1);
+        if armor.hits == 0 {
+            self.conditions.remove(index);
+        }
+    }
+    // Armor and projectile guards only last for the move that granted them
+    fn clear_move_conditions(&mut self) {
+        self.conditions.retain(|cond| {
+            !matches!(
+                cond.flag,
+                StatusFlag::Armor(_) | StatusFlag::ProjectileGuard(_)
+            )
+        });
+    }
+
     pub fn cancels(&self) -> Vec<CancelType> {
         self.conditions
             .clone()
@@ -398,6 +480,7 @@ impl PlayerState {
 #[cfg(test)]
 mod test {
     use super::*;
+    use foundation::{CharacterId, RoninAction};
 
     #[test]
     fn generic_animation_mid_move() {
@@ -420,4 +503,91 @@ mod test {
             Some(AnimationType::StandIdle)
         );
     }
+
+    #[test]
+    fn armor_breaks() {
+        let mut state = PlayerState::default();
+        state.add_condition(StatusCondition {
+            flag: StatusFlag::Armor(Armor::hits(2)),
+            ..default()
+        });
+
+        state.use_armor();
+        assert_eq!(state.armor().map(|armor| armor.hits), Some(1));
+
+        state.use_armor();
+        assert_eq!(state.armor(), None);
+    }
+
+    #[test]
+    fn cancel_drops_armor() {
+        let mut state = PlayerState::default();
+        state.start_move(ActionId::TestMove, 0);
+        state.add_condition(StatusCondition {
+            flag: StatusFlag::Armor(Armor::hits(2)),
+            ..default()
+        });
+
+        state.start_move(ActionId::SecondTestMove, 5);
+        assert_eq!(state.armor(), None);
+    }
+
+    #[test]
+    fn counter_hit_windows() {
+        let character = Character::from(CharacterId::Ronin);
+        let mut state = PlayerState::default();
+        assert_eq!(state.counter_hit(&character, false), None);
+
+        state.start_move(RoninAction::HighStab.into(), 0);
+        assert_eq!(
+            state.counter_hit(&character, false),
+            Some(CounterHit::Counter)
+        );
+
+        state.register_attack();
+        assert_eq!(state.counter_hit(&character, true), None);
+        assert_eq!(
+            state.counter_hit(&character, false),
+            Some(CounterHit::Punish)
+        );
+    }
+
+    #[test]
+    fn movement_is_not_counter_hit() {
+        let character = Character::from(CharacterId::Ronin);
+
+        for action in [
+            ActionId::NeutralJump,
+            ActionId::ForwardJump,
+            ActionId::ForwardDash,
+            ActionId::BackDash,
+        ] {
+            let mut state = PlayerState::default();
+            state.start_move(action, 0);
+            assert_eq!(state.counter_hit(&character, false), None, "{action:?}");
+        }
+    }
+
+    #[test]
+    fn missing_move_ends() {
+        // Ronin has no test move, like after a reload that removed the running move
+        let character = Character::from(CharacterId::Ronin);
+        let mut state = PlayerState::default();
+        state.start_move(ActionId::TestMove, 0);
+
+        let events = state.proceed_move(
+            Inventory::default(),
+            &character,
+            Gauges(vec![]),
+            InputParser::default(),
+            Stats::default(),
+            0,
+            0,
+            Vec3::ZERO,
+            CharacterFacing::default(),
+            Combo::default(),
+        );
+
+        assert!(matches!(events.as_slice(), [ActionEvent::End]));
+    }
 }
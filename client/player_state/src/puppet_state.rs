@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+
+use characters::{ActionEvent, ActionTracker, Character, Situation};
+use foundation::ActionId;
+
+/// Like PlayerState, but for summons that only do what they are told
+#[derive(Reflect, Debug, Component, Clone, Copy, Default)]
+pub struct PuppetState {
+    pub health: usize,
+    tracker: Option<ActionTracker>,
+}
+
+impl PuppetState {
+    pub fn new(health: usize) -> Self {
+        Self {
+            health,
+            ..default()
+        }
+    }
+
+    pub fn start_action(&mut self, action_id: ActionId, start_frame: usize) {
+        self.tracker = Some(ActionTracker::new(
+            start_frame,
+            self.action_in_progress(),
+            action_id,
+        ));
+    }
+
+    pub fn action_in_progress(&self) -> bool {
+        self.tracker.is_some()
+    }
+
+    pub fn register_attack(&mut self) {
+        if let Some(ref mut tracker) = self.tracker {
+            tracker.attacked = true;
+        }
+    }
+
+    /// Situation should be built from the owner, this fills in the puppet specific parts
+    pub fn proceed_action(
+        &mut self,
+        character: &Character,
+        situation: Situation,
+    ) -> Vec<ActionEvent> {
+        let Some(tracker) = self.tracker else {
+            return vec![];
+        };
+
+        let situation = Situation {
+            tracker: Some(tracker),
+            grounded: true,
+            ..situation
+        };
+
+        let Some(action) = character.get_move(tracker.action_id) else {
+            // The character was reloaded without this move
+            self.tracker = None;
+            return vec![];
+        };
+
+        let events = (action.script)(&situation);
+        if events.iter().any(|ev| matches!(ev, ActionEvent::End)) {
+            self.tracker = None;
+        }
+
+        events
+            .into_iter()
+            .filter(|ev| !matches!(ev, ActionEvent::End))
+            .collect()
+    }
+
+    /// Returns true if that did it in
+    pub fn take_hit(&mut self) -> bool {
+        self.health = self.health.saturating_sub(1);
+        self.health == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use foundation::{CharacterId, RoninAction};
+
+    use super::*;
+
+    #[test]
+    fn puppet_breaks() {
+        let mut puppet = PuppetState::new(2);
+        assert!(!puppet.take_hit());
+        assert!(puppet.take_hit());
+    }
+
+    #[test]
+    fn idle_puppet_does_nothing() {
+        let mut puppet = PuppetState::new(1);
+        let character = Character::from(CharacterId::Ronin);
+
+        assert!(puppet
+            .proceed_action(&character, Situation::default())
+            .is_empty());
+    }
+
+    #[test]
+    fn puppet_does_owner_move() {
+        let mut puppet = PuppetState::new(1);
+        let character = Character::from(CharacterId::Ronin);
+        puppet.start_action(RoninAction::HeelKick.into(), 10);
+
+        let mut spawned = false;
+        for frame in 10..100 {
+            let events = puppet.proceed_action(
+                &character,
+                Situation {
+                    char_frame: frame,
+                    stats: character.base_stats,
+                    ..default()
+                },
+            );
+            // The owner's action doesn't end when the puppet's does
+            assert!(!events.iter().any(|ev| matches!(ev, ActionEvent::End)));
+            spawned |= events
+                .iter()
+                .any(|ev| matches!(ev, ActionEvent::SpawnHitbox(_)));
+
+            if !puppet.action_in_progress() {
+                break;
+            }
+        }
+
+        assert!(spawned);
+        assert!(!puppet.action_in_progress());
+    }
+}